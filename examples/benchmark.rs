@@ -0,0 +1,96 @@
+/*! Times some semantics in both encodings on `PRESETS[3]` and on random frameworks:
+ *
+ * cargo run --release --no-default-features --example benchmark
+ */
+use std::time::Instant;
+
+use af::{
+    encoding::Enconding,
+    presets::PRESETS,
+    sat::FormulaEncoding,
+    semantics::{Semantics, SemanticsType},
+    Attack, AF,
+};
+
+/** A framework of `n` arguments where each attack is there with probability `density`,
+ * and answered by the opposite attack half of the time, always the same for the same `seed`
+ */
+fn generate(n: usize, density: f64, seed: u64) -> AF {
+    let mut state = seed;
+    let mut random = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    };
+    let mut attacks = vec![];
+    for origin in 0..n {
+        for target in 0..n {
+            if origin < target && random() < density {
+                attacks.push(Attack(origin, target));
+                if random() < 0.5 {
+                    attacks.push(Attack(target, origin));
+                }
+            }
+        }
+    }
+    // every argument exists even when it attacks nothing
+    if n > 0
+        && attacks
+            .iter()
+            .all(|Attack(o, t)| *o != n - 1 && *t != n - 1)
+    {
+        attacks.push(Attack(n - 1, n - 1));
+    }
+    AF::new(attacks)
+}
+
+fn main() {
+    let mut frameworks = vec![(
+        String::from("PRESETS[3]"),
+        AF::from(Enconding::parse(String::from(PRESETS[3]))),
+    )];
+    let sizes = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse::<usize>().ok())
+        .collect::<Vec<_>>();
+    for n in if sizes.is_empty() {
+        vec![30, 40, 50]
+    } else {
+        sizes
+    } {
+        let density = 3.0 / n as f64;
+        frameworks.push((
+            format!("n={} p={:.3}", n, density),
+            generate(n, density, n as u64),
+        ));
+    }
+    println!(
+        "{:<16} {:<12} {:>8} {:>12} {:>12}",
+        "framework", "semantics", "found", "labelling ms", "extension ms"
+    );
+    for (name, framework) in &frameworks {
+        for semantics in [
+            SemanticsType::STABLE,
+            SemanticsType::PREFERRED,
+            SemanticsType::SEMISTABLE,
+            SemanticsType::STAGE,
+            SemanticsType::IDEAL,
+        ] {
+            let mut found = 0;
+            let mut times = vec![];
+            for encoding in [FormulaEncoding::LABELLING, FormulaEncoding::EXTENSION] {
+                let framework = framework.clone().with_encoding(encoding);
+                let start = Instant::now();
+                found = framework.get_semantics(semantics).len();
+                times.push(start.elapsed().as_secs_f64() * 1000.0);
+            }
+            let semantics_name: String = semantics.into();
+            println!(
+                "{:<16} {:<12} {:>8} {:>12.1} {:>12.1}",
+                name, semantics_name, found, times[0], times[1]
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    semantics::{Acceptability::IN, Labelling, SemanticsType},
+    AF,
+};
+use crate::sat::{Formula, Lit};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "web", derive(serde::Serialize, serde::Deserialize))]
+pub struct Acceptance {
+    pub accepted: bool,
+    /** For credulous queries, a labelling where the argument is IN (when accepted).
+     * For skeptical queries, a labelling where the argument is not IN (when not accepted).
+     */
+    pub witness: Option<Labelling>,
+}
+
+impl AF {
+    /** Decides whether `arg` is IN in some labelling of `semantics` (DC).
+     * Whenever the problem is in NP this takes a single SAT call. For preferred and naive semantics the complete
+     * or conflict-free labelling found is then grown, with `arg` kept IN, into a maximal one.
+     * It fails when the solver does.
+     */
+    pub fn credulous(&self, arg: usize, semantics: SemanticsType) -> Result<Acceptance, String> {
+        let mut formula = self.create_formula();
+        match semantics {
+            SemanticsType::CONFLICTFREE => self.add_conflict_free_labelling_clauses(&mut formula),
+            SemanticsType::ADMISSIBLE | SemanticsType::COMPLETE => {
+                self.add_complete_clauses(&mut formula)
+            }
+            SemanticsType::PREFERRED | SemanticsType::NAIVE => {
+                match semantics {
+                    SemanticsType::PREFERRED => self.add_complete_clauses(&mut formula),
+                    _ => self.add_conflict_free_labelling_clauses(&mut formula),
+                }
+                // a maximal labelling among those with arg IN is maximal among all of them
+                let mut backend = self.sat.backend();
+                backend.add_formula(&formula.cnf);
+                backend.add_clause(&[formula.vars.i[arg].positive()]);
+                let witness = self.next_in_maximal(&mut formula, backend.as_mut())?;
+                return Ok(Acceptance {
+                    accepted: witness.is_some(),
+                    witness,
+                });
+            }
+            SemanticsType::STABLE => self.add_stable_clauses(&mut formula),
+            _ => {
+                let witness =
+                    self.find(semantics, |labelling| labelling.0.get(arg) == Some(&IN))?;
+                return Ok(Acceptance {
+                    accepted: witness.is_some(),
+                    witness,
+                });
+            }
+        }
+        let lit = formula.vars.i[arg].positive();
+        let witness = self.solve_with(formula, lit)?;
+        Ok(Acceptance {
+            accepted: witness.is_some(),
+            witness,
+        })
+    }
+
+    /** Decides whether `arg` is IN in every labelling of `semantics` (DS).
+     * Whenever the problem is in coNP this takes a single SAT call, looking for a counter-example.
+     * It fails when the solver does.
+     */
+    pub fn skeptical(&self, arg: usize, semantics: SemanticsType) -> Result<Acceptance, String> {
+        let mut formula = self.create_formula();
+        match semantics {
+            SemanticsType::CONFLICTFREE => self.add_conflict_free_labelling_clauses(&mut formula),
+            SemanticsType::ADMISSIBLE => self.add_admissible_clauses(&mut formula),
+            SemanticsType::COMPLETE => self.add_complete_clauses(&mut formula),
+            SemanticsType::STABLE => self.add_stable_clauses(&mut formula),
+            _ => {
+                let witness =
+                    self.find(semantics, |labelling| labelling.0.get(arg) != Some(&IN))?;
+                return Ok(Acceptance {
+                    accepted: witness.is_none(),
+                    witness,
+                });
+            }
+        }
+        let lit = formula.vars.i[arg].negative();
+        let witness = self.solve_with(formula, lit)?;
+        Ok(Acceptance {
+            accepted: witness.is_none(),
+            witness,
+        })
+    }
+
+    fn solve_with(&self, formula: Formula, lit: Lit) -> Result<Option<Labelling>, String> {
+        Ok(self
+            .sat
+            .solve_assuming(&formula.cnf, &[lit])?
+            .map(|model| self.label(&model)))
+    }
+
+    /** First labelling of `semantics` satisfying `predicate`, without looking for the ones after it */
+    fn find(
+        &self,
+        semantics: SemanticsType,
+        predicate: impl Fn(&Labelling) -> bool,
+    ) -> Result<Option<Labelling>, String> {
+        for labelling in self.labellings(semantics) {
+            let labelling = labelling?;
+            if predicate(&labelling) {
+                return Ok(Some(labelling));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -2,36 +2,205 @@ use wasm_bindgen::JsValue;
 use web_sys::{File, HtmlTextAreaElement, console::log_1};
 use yew::prelude::*;
 
-mod af;
+mod cache;
 mod components;
 mod glue;
 mod graph;
-mod sat;
+mod solver;
 mod util;
 
-use crate::app::{
-    af::{
-        encoding::Enconding,
-        semantics::{Semantics, SemanticsType},
-        AF,
+use af::{
+    encoding::Enconding,
+    graph::DotDrawable,
+    sat::FormulaEncoding,
+    semantics::{
+        Acceptability::{IN, OUT, UNDEC},
+        Labelling, SemanticsType,
     },
+    verification::Violation,
+    worker::Request,
+    AF,
+};
+
+use crate::app::{
+    cache::Cache,
+    components::acceptance::{AcceptancePanel, AcceptanceRow},
+    components::checker::{CheckRow, Checker},
+    components::diagnostics::Diagnostics,
+    components::download::{DownloadFile, DownloadMenu},
     components::file_input::FileInput,
     components::{preset::Presets, select::Select},
     graph::VisDrawable,
-    util::read_file,
+    solver::{Action, Solved, SolverWorker},
+    util::{download_file, read_file},
 };
 
+const GRAPHML_FILE: &str = "af.graphml";
+
 #[function_component(App)]
 pub fn app() -> Html {
     let textarea_ref = use_node_ref();
     let af_text_handle = use_state(|| String::from(""));
     let af_text = (*af_text_handle).clone();
-    let parsed = Enconding::parse_simple(af_text.clone());
-    let framework = AF::from(parsed);
+    // parsed again only when the text changes
+    let parsed = use_memo(af_text.clone(), |text| {
+        let parsed = Enconding::parse(text.clone());
+        let parse_errors = match &parsed {
+            Enconding::ERROR(errors) => errors.clone(),
+            _ => vec![],
+        };
+        let framework = AF::from(parsed);
+        let hash = framework.canonical_hash();
+        (parse_errors, framework, hash)
+    });
+    let (parse_errors, framework) = (parsed.0.clone(), &parsed.1);
     let semantics_type = use_state(|| SemanticsType::COMPLETE);
+    let encoding = use_state(FormulaEncoding::default);
     let vis_page = use_state(|| 0);
-    let semantics = framework.get_semantics(*semantics_type);
-    
+    let (solved, dispatch) = {
+        let solved = use_reducer(Solved::default);
+        let dispatch = solved.dispatcher();
+        (solved, dispatch)
+    };
+    let solver = use_mut_ref(|| None::<SolverWorker>);
+    let cache = use_mut_ref(Cache::default);
+    let semantics = solved.listed.clone();
+    let num_of_labellings = solved.count;
+    let semantics_options = vec![
+        SemanticsType::COMPLETE,
+        SemanticsType::GROUNDED,
+        SemanticsType::PREFERRED,
+        SemanticsType::STABLE,
+        SemanticsType::SEMISTABLE,
+        SemanticsType::STAGE,
+        SemanticsType::IDEAL,
+        SemanticsType::EAGER,
+        SemanticsType::NAIVE,
+        SemanticsType::CF2,
+        SemanticsType::STAGE2,
+        SemanticsType::ADMISSIBLE,
+        SemanticsType::CONFLICTFREE,
+    ];
+    let query_arg = use_state(|| None::<usize>);
+    let current_query_arg = (*query_arg).filter(|&arg| arg < framework.num_of_args);
+    let arg_names = framework.arg_names();
+    let acceptance_rows = match (&solved.acceptance, current_query_arg) {
+        (Some((arg, results)), Some(current)) if *arg == current => semantics_options
+            .iter()
+            .zip(results)
+            .map(|(&s, (credulous, skeptical))| {
+                let describe = |witness: &Option<Labelling>| {
+                    witness.as_ref().map(|labelling| {
+                        labelling
+                            .0
+                            .iter()
+                            .zip(&arg_names)
+                            .map(|(acc, name)| format!("{}: {:?}", name, acc))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                };
+                AcceptanceRow {
+                    semantics: s.into(),
+                    credulous: credulous.accepted,
+                    skeptical: skeptical.accepted,
+                    credulous_witness: describe(&credulous.witness),
+                    skeptical_witness: describe(&skeptical.witness),
+                }
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    // a new job whenever the framework, the semantics or the encoding change, stopping the previous one.
+    // The worker only looks for labellings that weren't already cached, and not at all once they all are
+    {
+        let solver = solver.clone();
+        let dispatch = dispatch.clone();
+        let cache = cache.clone();
+        let parsed = parsed.clone();
+        let job = solved.job + 1;
+        let wanted = *vis_page + 2;
+        use_effect_with(
+            (af_text.clone(), *semantics_type, *encoding),
+            move |(text, semantics_type, encoding)| {
+                let key = (parsed.2, (*semantics_type).into(), *encoding);
+                let cached = cache.borrow_mut().get(&parsed.1, key).unwrap_or_default();
+                let known = cached.listed.clone();
+                let wanted = if cached.exhausted { 0 } else { wanted };
+                let mut solver = solver.borrow_mut();
+                if solver.as_ref().is_none_or(|worker| worker.is_busy()) {
+                    *solver = SolverWorker::spawn(dispatch.clone());
+                }
+                dispatch.dispatch(Action::START(job, key, cached));
+                if let Some(worker) = solver.as_ref() {
+                    worker.send(Request::SOLVE {
+                        job,
+                        text: text.clone(),
+                        semantics: key.1,
+                        encoding: key.2,
+                        known,
+                        wanted,
+                    });
+                }
+            },
+        );
+    }
+
+    // cache what the worker answered whenever it's done
+    {
+        let cache = cache.clone();
+        let parsed = parsed.clone();
+        let solved = solved.clone();
+        let done = !solved.searching && !solved.counting;
+        use_effect_with(
+            (solved.job, solved.listed.len(), solved.count, done),
+            move |_| {
+                if done && solved.job > 0 && solved.key.0 == parsed.2 {
+                    cache
+                        .borrow_mut()
+                        .put(&parsed.1, solved.key, solved.entry());
+                }
+            },
+        );
+    }
+
+    // one more than the page on display, to know whether there's a next page
+    {
+        let solver = solver.clone();
+        let dispatch = dispatch.clone();
+        let job = solved.job;
+        let more = solved.listed.len() < *vis_page + 2 && !solved.exhausted;
+        use_effect_with(*vis_page, move |vis_page| {
+            if let (true, Some(worker)) = (more, solver.borrow().as_ref()) {
+                dispatch.dispatch(Action::SEARCHING);
+                worker.send(Request::MORE {
+                    job,
+                    wanted: *vis_page + 2,
+                });
+            }
+        });
+    }
+
+    {
+        let solver = solver.clone();
+        let dispatch = dispatch.clone();
+        let job = solved.job;
+        let semantics = semantics_options
+            .iter()
+            .map(|&s| s.into())
+            .collect::<Vec<usize>>();
+        use_effect_with((job, current_query_arg), move |(job, arg)| {
+            if let (Some(arg), Some(worker)) = (arg, solver.borrow().as_ref()) {
+                dispatch.dispatch(Action::ACCEPTING);
+                worker.send(Request::ACCEPTANCE {
+                    job: *job,
+                    arg: *arg,
+                    semantics,
+                });
+            }
+        });
+    }
 
     let load_af = {
         let af_text_handle = af_text_handle.clone();
@@ -59,6 +228,39 @@ pub fn app() -> Html {
         })
     };
 
+    let jump_to_line = {
+        let textarea_ref = textarea_ref.clone();
+        let af_text = af_text.clone();
+        Callback::from(move |line: usize| {
+            if let Some(textarea) = textarea_ref.cast::<HtmlTextAreaElement>() {
+                // selection offsets count UTF-16 code units
+                let mut start = 0;
+                let mut end = 0;
+                for (i, text) in af_text.split('\n').enumerate() {
+                    end = start + text.encode_utf16().count();
+                    if i + 1 == line {
+                        break;
+                    }
+                    start = end + 1;
+                }
+                let _ = textarea.focus();
+                let _ = textarea.set_selection_range(start as u32, end as u32);
+            }
+        })
+    };
+
+    let count_labellings = {
+        let solver = solver.clone();
+        let dispatch = dispatch.clone();
+        let job = solved.job;
+        Callback::from(move |_: MouseEvent| {
+            if let Some(worker) = solver.borrow().as_ref() {
+                dispatch.dispatch(Action::COUNTING);
+                worker.send(Request::COUNT { job });
+            }
+        })
+    };
+
     let prev_page = {
         let vis_page = vis_page.clone();
         Callback::from(move |_: MouseEvent| {
@@ -72,12 +274,171 @@ pub fn app() -> Html {
         let vis_page = vis_page.clone();
         let num_of_pages = semantics.len();
         Callback::from(move |_: MouseEvent| {
-            if *vis_page < num_of_pages - 1 {
+            if *vis_page + 1 < num_of_pages {
                 vis_page.set(*vis_page + 1);
             }
         })
     };
 
+    let edit_mode = use_state(|| false);
+    let manual_labelling_handle = use_state(|| Labelling(vec![]));
+    let manual_labelling = if manual_labelling_handle.0.len() == framework.num_of_args {
+        (*manual_labelling_handle).clone()
+    } else {
+        Labelling(vec![UNDEC; framework.num_of_args])
+    };
+    let checked_semantics = [
+        SemanticsType::CONFLICTFREE,
+        SemanticsType::ADMISSIBLE,
+        SemanticsType::COMPLETE,
+        SemanticsType::STABLE,
+    ];
+    let mut violations = vec![];
+    let check_rows = match *edit_mode {
+        true => checked_semantics
+            .iter()
+            .map(|&s| {
+                let result = framework.verify(&manual_labelling, s);
+                if let Err(Violation { arg: Some(arg), .. }) = &result {
+                    violations.push(*arg);
+                }
+                CheckRow {
+                    semantics: s.into(),
+                    violation: result.err().map(|violation| violation.to_string()),
+                }
+            })
+            .collect(),
+        false => vec![],
+    };
+
+    let toggle_edit_mode = {
+        let edit_mode = edit_mode.clone();
+        let manual_labelling_handle = manual_labelling_handle.clone();
+        // start editing from the labelling on display
+        let displayed = semantics
+            .get(*vis_page)
+            .filter(|labelling| labelling.0.len() == framework.num_of_args)
+            .cloned();
+        Callback::from(move |_: MouseEvent| {
+            if !*edit_mode {
+                if let Some(displayed) = &displayed {
+                    manual_labelling_handle.set(displayed.clone());
+                }
+            }
+            edit_mode.set(!*edit_mode);
+        })
+    };
+
+    let cycle_label = {
+        let manual_labelling_handle = manual_labelling_handle.clone();
+        let manual_labelling = manual_labelling.clone();
+        Callback::from(move |arg: usize| {
+            let mut labelling = manual_labelling.clone();
+            labelling.0[arg] = match labelling.0[arg] {
+                IN => OUT,
+                OUT => UNDEC,
+                UNDEC => IN,
+            };
+            manual_labelling_handle.set(labelling);
+        })
+    };
+
+    let displayed_labelling = match *edit_mode {
+        true => Some(&manual_labelling),
+        false => semantics.get(*vis_page),
+    };
+    // every file is written only when it's downloaded
+    let download = |write: fn(&AF, Option<&Labelling>) -> String| {
+        let parsed = parsed.clone();
+        let labelling = displayed_labelling.cloned();
+        Callback::from(move |_| Some(write(&parsed.1, labelling.as_ref())))
+    };
+    // every labelling of the semantics, not only the ones listed so far: the worker lists the rest first,
+    // and the file is downloaded once it's done
+    let download_graphml = {
+        let parsed = parsed.clone();
+        let solver = solver.clone();
+        let dispatch = dispatch.clone();
+        let listed = solved.exhausted.then(|| semantics.clone());
+        let job = solved.job;
+        Callback::from(move |_| match &listed {
+            Some(listed) => Some(parsed.1.to_graphml(listed)),
+            None => {
+                if let Some(worker) = solver.borrow().as_ref() {
+                    dispatch.dispatch(Action::EXPORTING);
+                    worker.send(Request::MORE {
+                        job,
+                        wanted: usize::MAX,
+                    });
+                }
+                None
+            }
+        })
+    };
+    {
+        let parsed = parsed.clone();
+        let dispatch = dispatch.clone();
+        let solved = solved.clone();
+        let ready = solved.exporting && solved.exhausted;
+        use_effect_with((solved.job, ready), move |(_, ready)| {
+            if *ready {
+                download_file(GRAPHML_FILE, &parsed.1.to_graphml(&solved.listed));
+                dispatch.dispatch(Action::EXPORTED);
+            }
+        });
+    }
+    let mut download_files = vec![
+        DownloadFile {
+            text: String::from("APX"),
+            filename: String::from("af.apx"),
+            content: download(|af, _| af.to_simple()),
+        },
+        DownloadFile {
+            text: String::from("TGF"),
+            filename: String::from("af.tgf"),
+            content: download(|af, _| af.to_tgf()),
+        },
+        DownloadFile {
+            text: String::from("AIF"),
+            filename: String::from("af.json"),
+            content: download(|af, labelling| af.to_aif(labelling)),
+        },
+        DownloadFile {
+            text: String::from("DOT"),
+            filename: String::from("af.dot"),
+            content: download(|af, labelling| af.to_dot(labelling)),
+        },
+        DownloadFile {
+            text: String::from("GraphML"),
+            filename: String::from(GRAPHML_FILE),
+            content: download_graphml,
+        },
+    ];
+    // the formula of the semantics on display, when it has one
+    if matches!(
+        *semantics_type,
+        SemanticsType::CONFLICTFREE
+            | SemanticsType::ADMISSIBLE
+            | SemanticsType::COMPLETE
+            | SemanticsType::STABLE
+    ) {
+        let parsed = parsed.clone();
+        let (semantics_type, encoding) = (*semantics_type, *encoding);
+        download_files.push(DownloadFile {
+            text: String::from("DIMACS"),
+            filename: String::from("af.cnf"),
+            content: Callback::from(move |_| {
+                let encoded = parsed.1.clone().with_encoding(encoding);
+                encoded.to_dimacs(semantics_type)
+            }),
+        });
+    }
+
+    let select_query_arg = {
+        let query_arg = query_arg.clone();
+        Callback::from(move |arg: usize| query_arg.set(Some(arg)))
+    };
+
     let load_preset = {
         let vis_page = vis_page.clone();
         let af_text_handle = af_text_handle.clone();
@@ -87,8 +448,23 @@ pub fn app() -> Html {
         })
     };
 
-    // Synchronize the network visualization
-    framework.update_vis("af-graph", semantics.get(*vis_page));
+    // Synchronize the network visualization, only when what it shows changes
+    {
+        let parsed = parsed.clone();
+        let labelling = displayed_labelling.cloned();
+        use_effect_with(
+            (af_text.clone(), labelling, violations.clone(), *edit_mode),
+            move |(_, labelling, violations, edit_mode)| {
+                let onclick = match edit_mode {
+                    true => Some(cycle_label),
+                    false => None,
+                };
+                parsed
+                    .1
+                    .update_vis("af-graph", labelling.as_ref(), violations, onclick);
+            },
+        );
+    }
     let flex_row = util::flex_row();
     let flex_col = util::flex_col();
 
@@ -98,19 +474,48 @@ pub fn app() -> Html {
                 <div class={classes!(flex_col.clone())}>
                     <div class={classes!(flex_row.clone())}>
                         <FileInput id="load-af" text="Load AF" onload={load_af} />
+                        <DownloadMenu files={download_files} />
                         <Presets onselect={load_preset} />
                     </div>
                     <textarea class={
                         classes!("w-48", "h-64", "p-2", "border-2", "border-r-emerald-900", "border-solid", "resize-none")
                     } ref={textarea_ref} value={af_text} onchange={handle_af_text_change} />
+                    <Diagnostics errors={parse_errors} onselect={jump_to_line} />
                 </div>
-                <div class={classes!(flex_col)}>
+                <div class={classes!(flex_col.clone())}>
                     <div class={classes!(flex_row.clone())}>
-                        <i onclick={prev_page} class={classes!("fa-solid", "fa-arrow-left", "cursor-pointer")}></i>
-                        <p>{ format!("{}/{}", 1 + *vis_page, semantics.len()) }</p>
-                        <i onclick={next_page} class={classes!("fa-solid", "fa-arrow-right", "cursor-pointer")}></i>
+                        if *edit_mode {
+                            <p>{ "Click an argument to change its label" }</p>
+                        } else {
+                            <i onclick={prev_page} class={classes!("fa-solid", "fa-arrow-left", "cursor-pointer")}></i>
+                            if let Some(num_of_labellings) = num_of_labellings {
+                                <p>{ format!("{}/{}", 1 + *vis_page, num_of_labellings) }</p>
+                            } else {
+                                <p>{ format!("{}/{}+", 1 + *vis_page, semantics.len()) }</p>
+                            }
+                            <i onclick={next_page} class={classes!("fa-solid", "fa-arrow-right", "cursor-pointer")}></i>
+                            if solved.is_busy() {
+                                <i class={classes!("fa-solid", "fa-spinner", "fa-spin")}></i>
+                            }
+                            if let Some(error) = &solved.error {
+                                <p class={classes!("text-red-700")}>{ error }</p>
+                            }
+                            if num_of_labellings.is_none() && !solved.counting {
+                                <button onclick={count_labellings}
+                                    class={classes!("m-1", "p-2", "bg-teal-600", "text-stone-100", "rounded")}>
+                                    { "Count" }
+                                </button>
+                            }
+                        }
+                        <button onclick={toggle_edit_mode}
+                            class={classes!("m-1", "p-2", "bg-teal-600", "text-stone-100", "rounded")}>
+                            { if *edit_mode { "Back to semantics" } else { "Check a labelling" } }
+                        </button>
                     </div>
                     <div style="border: 2px solid black;width:512px;height:512px;" id="af-graph"></div>
+                    if *edit_mode {
+                        <Checker rows={check_rows} />
+                    }
                     <div class={classes!(flex_row.clone())}>
                         <label>{ "Semantics:" }</label>
                         <Select<SemanticsType>
@@ -119,13 +524,24 @@ pub fn app() -> Html {
                                 Callback::from(move |s| semantics_type.set(s))
                             }}
                             current={*semantics_type}
-                            options={vec![
-                                SemanticsType::COMPLETE,
-                                SemanticsType::PREFERRED,
-                                SemanticsType::STABLE,
-                            ]} />
+                            options={semantics_options} />
+                        <label>{ "Encoding:" }</label>
+                        <Select<FormulaEncoding>
+                            onchange={{
+                                let encoding = encoding.clone();
+                                Callback::from(move |e| encoding.set(e))
+                            }}
+                            current={*encoding}
+                            options={vec![FormulaEncoding::LABELLING, FormulaEncoding::EXTENSION]} />
                     </div>
                 </div>
+                <div class={classes!(flex_col)}>
+                    <AcceptancePanel
+                        args={arg_names}
+                        current={current_query_arg}
+                        onselect={select_query_arg}
+                        rows={acceptance_rows} />
+                </div>
             </div>
         </div>
     }
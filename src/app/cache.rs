@@ -0,0 +1,157 @@
+/*! Labellings already computed, by framework and semantics, kept across renders and in localStorage */
+use std::collections::HashMap;
+
+use af::{
+    sat::FormulaEncoding,
+    semantics::{
+        Acceptability::{IN, OUT, UNDEC},
+        Labelling,
+    },
+    AF,
+};
+use serde::{Deserialize, Serialize};
+use web_sys::Storage;
+
+/** Canonical hash of a framework, index of a semantics and the encoding it's solved with,
+ * which decides the order of the labellings
+ */
+pub type Key = (u64, usize, FormulaEncoding);
+
+/** What is known of a semantics of a framework */
+#[derive(Default, Clone)]
+pub struct Entry {
+    pub listed: Vec<Labelling>,
+    pub exhausted: bool,
+    pub count: Option<usize>,
+}
+
+/* labellings as strings of I, O and U, in canonical order */
+#[derive(Serialize, Deserialize)]
+struct Stored {
+    listed: Vec<String>,
+    exhausted: bool,
+    count: Option<usize>,
+}
+
+/* labellings after these are only kept in memory */
+const MAX_STORED: usize = 256;
+
+/** Entries with arguments in canonical order, so that they still apply when the framework is written
+ * in another order, and are given back in the order of the framework asking for them
+ */
+#[derive(Default)]
+pub struct Cache {
+    entries: HashMap<Key, Entry>,
+}
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+fn storage_key((hash, semantics, encoding): Key) -> String {
+    format!("af:{:016x}:{}:{}", hash, semantics, encoding as usize)
+}
+
+/** `labelling` with the arguments of `order` */
+fn to_canonical(order: &[usize], labelling: &Labelling) -> Labelling {
+    Labelling(order.iter().map(|&arg| labelling.0[arg].clone()).collect())
+}
+
+/** `labelling` with canonical arguments placed back where `order` says */
+fn from_canonical(order: &[usize], labelling: &Labelling) -> Option<Labelling> {
+    if labelling.0.len() != order.len() {
+        return None;
+    }
+    let mut labels = vec![UNDEC; order.len()];
+    for (&arg, label) in order.iter().zip(&labelling.0) {
+        labels[arg] = label.clone();
+    }
+    Some(Labelling(labels))
+}
+
+impl Cache {
+    /** The entry of `key`, from memory or else from localStorage, in the order of `framework` */
+    pub fn get(&mut self, framework: &AF, key: Key) -> Option<Entry> {
+        let canonical = match self.entries.get(&key) {
+            Some(entry) => entry.clone(),
+            None => {
+                let entry = load(key)?;
+                self.entries.insert(key, entry.clone());
+                entry
+            }
+        };
+        let order = framework.canonical_order();
+        Some(Entry {
+            listed: canonical
+                .listed
+                .iter()
+                .map(|labelling| from_canonical(&order, labelling))
+                .collect::<Option<Vec<_>>>()?,
+            ..canonical
+        })
+    }
+
+    /** Remembers `entry`, listed in the order of `framework`, and stores it for the next visits */
+    pub fn put(&mut self, framework: &AF, key: Key, entry: Entry) {
+        let order = framework.canonical_order();
+        let canonical = Entry {
+            listed: entry
+                .listed
+                .iter()
+                .map(|labelling| to_canonical(&order, labelling))
+                .collect(),
+            ..entry
+        };
+        let stored = Stored {
+            listed: canonical
+                .listed
+                .iter()
+                .take(MAX_STORED)
+                .map(|labelling| {
+                    labelling
+                        .0
+                        .iter()
+                        .map(|label| match label {
+                            IN => 'I',
+                            OUT => 'O',
+                            UNDEC => 'U',
+                        })
+                        .collect()
+                })
+                .collect(),
+            exhausted: canonical.exhausted && canonical.listed.len() <= MAX_STORED,
+            count: canonical.count,
+        };
+        if let (Some(storage), Ok(json)) = (storage(), serde_json::to_string(&stored)) {
+            // a full storage only loses the cache
+            let _ = storage.set_item(&storage_key(key), &json);
+        }
+        self.entries.insert(key, canonical);
+    }
+}
+
+/** The entry of `key` stored by an earlier visit, in canonical order */
+fn load(key: Key) -> Option<Entry> {
+    let json = storage()?.get_item(&storage_key(key)).ok()??;
+    let stored: Stored = serde_json::from_str(&json).ok()?;
+    let listed = stored
+        .listed
+        .iter()
+        .map(|text| {
+            text.chars()
+                .map(|label| match label {
+                    'I' => Some(IN),
+                    'O' => Some(OUT),
+                    'U' => Some(UNDEC),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(Labelling)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(Entry {
+        listed,
+        exhausted: stored.exhausted,
+        count: stored.count,
+    })
+}
@@ -1,4 +1,8 @@
 
+pub mod acceptance;
+pub mod checker;
+pub mod diagnostics;
+pub mod download;
 pub mod file_input;
 pub mod preset;
 pub mod select;
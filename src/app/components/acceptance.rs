@@ -0,0 +1,76 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+#[derive(PartialEq, Clone)]
+pub struct AcceptanceRow {
+    pub semantics: String,
+    pub credulous: bool,
+    pub skeptical: bool,
+    /* witness or counter-example, as shown on hover */
+    pub credulous_witness: Option<String>,
+    pub skeptical_witness: Option<String>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AcceptanceProps {
+    pub args: Vec<String>,
+    pub current: Option<usize>,
+    pub onselect: Callback<usize>,
+    pub rows: Vec<AcceptanceRow>,
+}
+
+fn status(accepted: bool) -> Html {
+    if accepted {
+        html! { <i class={classes!("fa-solid", "fa-check", "text-emerald-700")}></i> }
+    } else {
+        html! { <i class={classes!("fa-solid", "fa-xmark", "text-red-700")}></i> }
+    }
+}
+
+#[function_component]
+pub fn AcceptancePanel(props: &AcceptanceProps) -> Html {
+    let select = use_node_ref();
+    let handle_change = {
+        let select = select.clone();
+        let onselect = props.onselect.clone();
+        Callback::from(move |_| {
+            let node = select.cast::<HtmlSelectElement>();
+            if let Some(Ok(i)) = node.map(|node| node.value().parse::<usize>()) {
+                onselect.emit(i);
+            }
+        })
+    };
+    html! {
+        <div class={classes!("flex", "flex-col", "gap-1", "items-center")}>
+            <div class={classes!("flex", "flex-row", "gap-1", "items-center")}>
+                <label>{ "Argument:" }</label>
+                <select class={classes!("m-1", "p-1")} ref={select} onchange={handle_change}>
+                    <option value="" selected={props.current.is_none()} disabled=true>{ "-" }</option>
+                    {
+                        props.args.iter().enumerate().map(|(i, arg)| html! {
+                            <option value={i.to_string()} selected={props.current == Some(i)}>{ arg }</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            if props.current.is_some() {
+                <table class={classes!("text-center")}>
+                    <tr>
+                        <th class={classes!("px-2")}>{ "Semantics" }</th>
+                        <th class={classes!("px-2")}>{ "Credulous" }</th>
+                        <th class={classes!("px-2")}>{ "Skeptical" }</th>
+                    </tr>
+                    {
+                        props.rows.iter().map(|row| html! {
+                            <tr key={row.semantics.clone()}>
+                                <td>{ row.semantics.clone() }</td>
+                                <td title={row.credulous_witness.clone()}>{ status(row.credulous) }</td>
+                                <td title={row.skeptical_witness.clone()}>{ status(row.skeptical) }</td>
+                            </tr>
+                        }).collect::<Html>()
+                    }
+                </table>
+            }
+        </div>
+    }
+}
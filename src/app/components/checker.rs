@@ -0,0 +1,38 @@
+use yew::prelude::*;
+
+#[derive(PartialEq, Clone)]
+pub struct CheckRow {
+    pub semantics: String,
+    /* why the labelling is not of this semantics */
+    pub violation: Option<String>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CheckerProps {
+    pub rows: Vec<CheckRow>,
+}
+
+#[function_component]
+pub fn Checker(props: &CheckerProps) -> Html {
+    html! {
+        <ul class={classes!("w-full")}>
+            {
+                props.rows.iter().map(|row| {
+                    let (icon, color) = match row.violation {
+                        None => ("fa-check", "text-emerald-700"),
+                        Some(_) => ("fa-xmark", "text-red-700"),
+                    };
+                    html! {
+                        <li key={row.semantics.clone()}>
+                            <i class={classes!("fa-solid", icon, color, "mr-1")}></i>
+                            <span>{ row.semantics.clone() }</span>
+                            if let Some(violation) = &row.violation {
+                                <span class={classes!("ml-1", "text-stone-500")}>{ format!("({})", violation) }</span>
+                            }
+                        </li>
+                    }
+                }).collect::<Html>()
+            }
+        </ul>
+    }
+}
@@ -0,0 +1,32 @@
+use yew::prelude::*;
+
+use af::encoding::ParseError;
+
+#[derive(Properties, PartialEq)]
+pub struct DiagnosticsProps {
+    pub errors: Vec<ParseError>,
+    /* receives the line of the clicked error */
+    pub onselect: Callback<usize>,
+}
+
+#[function_component]
+pub fn Diagnostics(props: &DiagnosticsProps) -> Html {
+    html! {
+        <ul class={classes!("w-48", "text-sm", "text-red-700")}>
+            {
+                props.errors.iter().map(|error| {
+                    let handle_click = {
+                        let onselect = props.onselect.clone();
+                        let line = error.line;
+                        Callback::from(move |_: MouseEvent| onselect.emit(line))
+                    };
+                    html! {
+                        <li onclick={handle_click} class={classes!("cursor-pointer", "hover:underline")}>
+                            { format!("{}:{} {}", error.line, error.column, error.kind) }
+                        </li>
+                    }
+                }).collect::<Html>()
+            }
+        </ul>
+    }
+}
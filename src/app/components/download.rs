@@ -0,0 +1,47 @@
+use yew::prelude::*;
+
+use crate::app::util::download_file;
+
+#[derive(PartialEq, Clone)]
+pub struct DownloadFile {
+    pub text: String,
+    pub filename: String,
+    /* built only when the file is downloaded, or None when it's downloaded later, once ready */
+    pub content: Callback<(), Option<String>>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DownloadMenuProps {
+    pub files: Vec<DownloadFile>,
+}
+
+#[function_component]
+pub fn DownloadMenu(props: &DownloadMenuProps) -> Html {
+    html! {
+        <details class={classes!("relative", "m-2")}>
+            <summary class={classes!("p-3", "bg-emerald-700", "rounded", "text-stone-100", "cursor-pointer", "list-none")}>
+                { "Download as…" }
+            </summary>
+            <ul class={classes!("absolute", "z-10", "bg-stone-100", "border", "rounded", "shadow")}>
+                {
+                    props.files.iter().map(|file| {
+                        let handle_click = {
+                            let file = file.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                if let Some(content) = file.content.emit(()) {
+                                    download_file(&file.filename, &content);
+                                }
+                            })
+                        };
+                        html! {
+                            <li key={file.filename.clone()} onclick={handle_click}
+                                class={classes!("px-3", "py-1", "cursor-pointer", "hover:bg-teal-600", "hover:text-stone-100", "whitespace-nowrap")}>
+                                { file.text.clone() }
+                            </li>
+                        }
+                    }).collect::<Html>()
+                }
+            </ul>
+        </details>
+    }
+}
@@ -1,8 +1,9 @@
 use wasm_bindgen::prelude::*;
 
 use js_sys::Array;
+use yew::Callback;
 
-use super::af::Attack;
+use af::Attack;
 
 /* Transform Vec to JsValue */
 fn str_array_js(v: Vec<String>) -> JsValue {
@@ -26,6 +27,8 @@ extern "C" {
         attack_origin: JsValue,
         attack_target: JsValue,
         colors: JsValue,
+        borders: JsValue,
+        on_click: JsValue,
     );
 }
 
@@ -34,6 +37,8 @@ pub fn update_vis_network(
     labels: Vec<String>,
     attacks: &Vec<Attack>,
     colors: Vec<String>,
+    borders: Vec<String>,
+    on_click: Option<Callback<usize>>,
 ) {
     let c = JsValue::from_str(container_id);
     let l = str_array_js(labels);
@@ -46,5 +51,13 @@ pub fn update_vis_network(
     let o = usize_array_js(attack_origin);
     let t = usize_array_js(attack_target);
     let colors = str_array_js(colors);
-    updateVisNetwork(c, l, o, t, colors);
+    let borders = str_array_js(borders);
+    // forward node clicks back into Rust, the closure is then owned by the JS side
+    let on_click = match on_click {
+        Some(callback) => {
+            Closure::<dyn Fn(usize)>::new(move |node: usize| callback.emit(node)).into_js_value()
+        }
+        None => JsValue::NULL,
+    };
+    updateVisNetwork(c, l, o, t, colors, borders, on_click);
 }
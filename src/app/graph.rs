@@ -1,27 +1,42 @@
-use super::{
-    af::{
-        semantics::{Acceptability, Labelling},
-        AF,
-    },
-    glue::update_vis_network,
-};
+use yew::Callback;
+
+use af::{graph::color_by_acceptability, semantics::Labelling, AF};
+
+use super::glue::update_vis_network;
 
 pub trait VisDrawable {
-    fn update_vis(&self, id: &str, labelling: Option<&Labelling>);
+    /** Draws the framework coloured by `labelling`, highlighting the `violations`.
+     * `on_click` receives the index of every clicked argument.
+     */
+    fn update_vis(
+        &self,
+        id: &str,
+        labelling: Option<&Labelling>,
+        violations: &[usize],
+        on_click: Option<Callback<usize>>,
+    );
 }
 
-fn color_by_acceptability(acc: &Acceptability) -> String {
-    match acc {
-        Acceptability::IN => String::from("green"),
-        Acceptability::OUT => String::from("red"),
-        Acceptability::UNDEC => String::from("blue"),
+fn border_by_violation(violated: bool) -> String {
+    match violated {
+        true => String::from("orange"),
+        false => String::from(""),
     }
 }
 
 impl VisDrawable for AF {
-    fn update_vis(&self, id: &str, labelling: Option<&Labelling>) {
+    fn update_vis(
+        &self,
+        id: &str,
+        labelling: Option<&Labelling>,
+        violations: &[usize],
+        on_click: Option<Callback<usize>>,
+    ) {
         let mut labels: Vec<String> = vec![];
         let mut colors: Vec<String> = vec![];
+        let borders = (0..self.num_of_args)
+            .map(|i| border_by_violation(violations.contains(&i)))
+            .collect();
         if let Some(_labels) = self.names_by_index() {
             for (i, &label) in _labels.iter().enumerate() {
                 labels.push(label.to_owned());
@@ -35,6 +50,6 @@ impl VisDrawable for AF {
                 labels.push(i.to_string());
             }
         }
-        update_vis_network(id, labels, &self.attacks, colors);
+        update_vis_network(id, labels, &self.attacks, colors, borders, on_click);
     }
 }
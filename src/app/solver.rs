@@ -0,0 +1,170 @@
+use std::{cell::Cell, rc::Rc};
+
+use af::{
+    acceptance::Acceptance,
+    semantics::Labelling,
+    worker::{Request, Response},
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{MessageEvent, Worker};
+use yew::{Reducible, UseReducerDispatcher};
+
+use crate::app::cache::{Entry, Key};
+
+/** What the worker has answered so far for the current job */
+#[derive(Default, Clone)]
+pub struct Solved {
+    pub job: u32,
+    /* the framework and semantics being solved */
+    pub key: Key,
+    pub listed: Vec<Labelling>,
+    pub exhausted: bool,
+    pub searching: bool,
+    pub count: Option<usize>,
+    pub counting: bool,
+    /* acceptance of an argument under each semantics, credulous then skeptical */
+    pub acceptance: Option<(usize, Vec<(Acceptance, Acceptance)>)>,
+    pub accepting: bool,
+    /* every labelling is being listed, to be exported once there are no more */
+    pub exporting: bool,
+    /* why the solver failed, which ends whatever it was doing */
+    pub error: Option<String>,
+}
+
+pub enum Action {
+    /* a new job, forgetting everything about the previous one but what was cached for it */
+    START(u32, Key, Entry),
+    SEARCHING,
+    COUNTING,
+    ACCEPTING,
+    EXPORTING,
+    EXPORTED,
+    RESPONSE(Response),
+}
+
+impl Solved {
+    pub fn is_busy(&self) -> bool {
+        self.searching || self.counting || self.accepting || self.exporting
+    }
+
+    pub fn entry(&self) -> Entry {
+        Entry {
+            listed: self.listed.clone(),
+            exhausted: self.exhausted,
+            count: self.count,
+        }
+    }
+}
+
+impl Reducible for Solved {
+    type Action = Action;
+
+    fn reduce(self: Rc<Self>, action: Action) -> Rc<Self> {
+        let mut solved = (*self).clone();
+        match action {
+            Action::START(job, key, cached) => {
+                solved = Solved {
+                    job,
+                    key,
+                    listed: cached.listed,
+                    exhausted: cached.exhausted,
+                    count: cached.count,
+                    searching: !cached.exhausted,
+                    ..Default::default()
+                }
+            }
+            Action::SEARCHING => solved.searching = !solved.exhausted,
+            Action::COUNTING => solved.counting = true,
+            Action::ACCEPTING => solved.accepting = true,
+            Action::EXPORTING => {
+                solved.searching = !solved.exhausted;
+                solved.exporting = true;
+            }
+            Action::EXPORTED => solved.exporting = false,
+            // answers to an older job
+            Action::RESPONSE(response) if response.job() != solved.job => return self,
+            Action::RESPONSE(response) => match response {
+                Response::FOUND { labelling, .. } => solved.listed.push(labelling),
+                Response::LISTED { .. } => solved.searching = false,
+                Response::EXHAUSTED { .. } => {
+                    solved.searching = false;
+                    solved.exhausted = true;
+                    solved.count = Some(solved.listed.len());
+                }
+                Response::COUNTED { count, .. } => {
+                    solved.counting = false;
+                    solved.count = Some(count);
+                }
+                Response::ACCEPTED { arg, results, .. } => {
+                    solved.accepting = false;
+                    solved.acceptance = Some((arg, results));
+                }
+                Response::FAILED { error, .. } => {
+                    solved.searching = false;
+                    solved.counting = false;
+                    solved.accepting = false;
+                    solved.exporting = false;
+                    solved.error = Some(error);
+                }
+                Response::STALE { .. } => {}
+            },
+        }
+        Rc::new(solved)
+    }
+}
+
+/** The worker solving in the background, terminated when dropped */
+pub struct SolverWorker {
+    worker: Worker,
+    /* requests not answered yet */
+    pending: Rc<Cell<usize>>,
+    _onmessage: Closure<dyn Fn(MessageEvent)>,
+}
+
+impl SolverWorker {
+    pub fn spawn(dispatch: UseReducerDispatcher<Solved>) -> Option<SolverWorker> {
+        // built by trunk from src/bin/worker.rs
+        let worker = Worker::new("worker_loader.js").ok()?;
+        let pending = Rc::new(Cell::new(0usize));
+        let onmessage = {
+            let pending = pending.clone();
+            Closure::<dyn Fn(MessageEvent)>::new(move |event: MessageEvent| {
+                let response = event
+                    .data()
+                    .as_string()
+                    .and_then(|json| Response::from_json(&json));
+                if let Some(response) = response {
+                    // every request is answered by exactly one response other than FOUND
+                    if !matches!(response, Response::FOUND { .. }) {
+                        pending.set(pending.get().saturating_sub(1));
+                    }
+                    dispatch.dispatch(Action::RESPONSE(response));
+                }
+            })
+        };
+        worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        Some(SolverWorker {
+            worker,
+            pending,
+            _onmessage: onmessage,
+        })
+    }
+
+    pub fn send(&self, request: Request) {
+        self.pending.set(self.pending.get() + 1);
+        let _ = self
+            .worker
+            .post_message(&JsValue::from_str(&request.to_json()));
+    }
+
+    /** Whether the worker is still computing, in which case it can only be stopped by terminating it */
+    pub fn is_busy(&self) -> bool {
+        self.pending.get() > 0
+    }
+}
+
+impl Drop for SolverWorker {
+    fn drop(&mut self) {
+        self.worker.terminate();
+    }
+}
@@ -1,4 +1,6 @@
-use web_sys::File;
+use js_sys::Array;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, File, HtmlAnchorElement, Url};
 use yew::{classes, Classes};
 
 pub async fn read_file(file: File) -> Option<String> {
@@ -11,6 +13,23 @@ pub async fn read_file(file: File) -> Option<String> {
     }
 }
 
+/** Makes the browser download `content` as a file named `filename` */
+pub fn download_file(filename: &str, content: &str) {
+    let parts = Array::of1(&JsValue::from_str(content));
+    let url = Blob::new_with_str_sequence(&parts)
+        .and_then(|blob| Url::create_object_url_with_blob(&blob));
+    let anchor = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok());
+    if let (Ok(url), Some(anchor)) = (url, anchor) {
+        anchor.set_href(&url);
+        anchor.set_download(filename);
+        anchor.click();
+        let _ = Url::revoke_object_url(&url);
+    }
+}
+
 pub fn flex_row() -> Classes {
     classes!("flex", "flex-row", "gap-1", "justify-center", "items-center")
 }
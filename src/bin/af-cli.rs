@@ -0,0 +1,197 @@
+/*! Command-line solver following the ICCMA probo interface, e.g.
+ *
+ *   af-cli -p DC-CO -f framework.apx -fo apx -a a
+ *
+ * Results are printed in the ICCMA 2019 output syntax.
+ * `-sat "kissat -q"` solves with a DIMACS solver binary instead of varisat,
+ * and `-enc extension` with one variable per argument (and the arguments it defeats) instead of three.
+ */
+use std::{
+    env, fs,
+    io::{self, Write},
+    process::exit,
+};
+
+use af::{
+    encoding::Enconding,
+    sat::{FormulaEncoding, SAT},
+    semantics::{Acceptability::IN, Labelling, SemanticsType, SEMANTICS_ABBREVIATION},
+    AF,
+};
+
+const FORMATS: [&str; 6] = ["apx", "tgf", "i23", "dot", "aif", "graphml"];
+
+const TASKS: [&str; 5] = ["SE", "EE", "DC", "DS", "CE"];
+
+fn fail(message: &str) -> ! {
+    eprintln!("af-cli: {}", message);
+    exit(1)
+}
+
+fn parse(text: String, format: Option<&str>) -> Enconding {
+    match format {
+        Some("apx") => Enconding::parse_simple(text),
+        Some("tgf") => Enconding::parse_tgf(text),
+        Some("i23") => Enconding::parse_iccma(text),
+        Some("dot") => Enconding::parse_dot(text),
+        Some("aif") => Enconding::parse_aif(text),
+        Some("graphml") => Enconding::parse_graphml(text),
+        Some(format) => fail(&format!("unknown format {}, see --formats", format)),
+        None => Enconding::parse(text),
+    }
+}
+
+/** `[a,b]`, the IN arguments of `labelling` */
+fn extension(names: &[String], labelling: &Labelling) -> String {
+    let args = labelling
+        .0
+        .iter()
+        .zip(names)
+        .filter(|(acc, _)| **acc == IN)
+        .map(|(_, name)| name.as_str())
+        .collect::<Vec<_>>();
+    format!("[{}]", args.join(","))
+}
+
+fn yes_or_no(accepted: bool) -> &'static str {
+    match accepted {
+        true => "YES",
+        false => "NO",
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.is_empty() {
+        println!("af-cli {}", env!("CARGO_PKG_VERSION"));
+        println!("{}", env!("CARGO_PKG_HOMEPAGE"));
+        return;
+    }
+    let (mut problem, mut file, mut format, mut query) = (None, None, None, None);
+    let (mut solver, mut encoding) = (None, None);
+    let mut i = 0;
+    while i < args.len() {
+        let value = args.get(i + 1).map(|value| value.as_str());
+        match args[i].as_str() {
+            "--formats" => {
+                println!("[{}]", FORMATS.join(","));
+                return;
+            }
+            "--problems" => {
+                let problems = TASKS
+                    .iter()
+                    .flat_map(|task| {
+                        SEMANTICS_ABBREVIATION
+                            .iter()
+                            .map(move |s| format!("{}-{}", task, s))
+                    })
+                    .collect::<Vec<_>>();
+                println!("[{}]", problems.join(","));
+                return;
+            }
+            "-p" => problem = value,
+            "-f" => file = value,
+            "-fo" => format = value,
+            "-a" => query = value,
+            "-sat" => solver = value,
+            "-enc" => encoding = value,
+            other => fail(&format!("unknown option {}", other)),
+        }
+        if value.is_none() {
+            fail(&format!("{} needs a value", args[i]));
+        }
+        i += 2;
+    }
+
+    let Some(problem) = problem else {
+        fail("missing -p");
+    };
+    let Some((task, abbreviation)) = problem.split_once('-') else {
+        fail(&format!("unknown problem {}, see --problems", problem));
+    };
+    let Some(semantics) = SemanticsType::from_abbreviation(abbreviation) else {
+        fail(&format!(
+            "unknown semantics {}, see --problems",
+            abbreviation
+        ));
+    };
+    let Some(file) = file else {
+        fail("missing -f");
+    };
+    let text = match fs::read_to_string(file) {
+        Ok(text) => text,
+        Err(error) => fail(&format!("cannot read {}, {}", file, error)),
+    };
+    let framework = match parse(text, format) {
+        Enconding::ERROR(errors) => {
+            for error in &errors {
+                eprintln!("{}:{}", file, error);
+            }
+            exit(1)
+        }
+        parsed => AF::from(parsed),
+    };
+    let framework = match encoding {
+        Some("labelling") | None => framework,
+        Some("extension") => framework.with_encoding(FormulaEncoding::EXTENSION),
+        Some(encoding) => fail(&format!(
+            "unknown encoding {}, either labelling or extension",
+            encoding
+        )),
+    };
+    let framework = match solver {
+        Some(command) => framework.with_solver(SAT::EXTERNAL(
+            command.split_whitespace().map(String::from).collect(),
+        )),
+        None => framework,
+    };
+    let names = framework.arg_names();
+    let arg = || {
+        let Some(query) = query else {
+            fail("missing -a");
+        };
+        match names.iter().position(|name| name == query) {
+            Some(arg) => arg,
+            None => fail(&format!("unknown argument {}", query)),
+        }
+    };
+
+    match task {
+        "SE" => match framework.labellings(semantics).next() {
+            Some(Ok(labelling)) => println!("{}", extension(&names, &labelling)),
+            Some(Err(error)) => fail(&error),
+            None => println!("NO"),
+        },
+        "EE" => {
+            // extensions are printed as soon as they're found, the bracket with the first one so that
+            // nothing is printed when the solver fails right away
+            let mut listed = 0;
+            for labelling in framework.labellings(semantics) {
+                let labelling = labelling.unwrap_or_else(|error| fail(&error));
+                print!("{}", if listed == 0 { "[" } else { "," });
+                print!("{}", extension(&names, &labelling));
+                let _ = io::stdout().flush();
+                listed += 1;
+            }
+            if listed == 0 {
+                print!("[");
+            }
+            println!("]");
+        }
+        "DC" => {
+            let acceptance = framework.credulous(arg(), semantics);
+            let acceptance = acceptance.unwrap_or_else(|error| fail(&error));
+            println!("{}", yes_or_no(acceptance.accepted))
+        }
+        "DS" => {
+            let acceptance = framework.skeptical(arg(), semantics);
+            let acceptance = acceptance.unwrap_or_else(|error| fail(&error));
+            println!("{}", yes_or_no(acceptance.accepted))
+        }
+        "CE" => {
+            let count = framework.count(semantics);
+            println!("{}", count.unwrap_or_else(|error| fail(&error)))
+        }
+        _ => fail(&format!("unknown problem {}, see --problems", problem)),
+    }
+}
@@ -0,0 +1,142 @@
+/*! Web Worker parsing frameworks and solving them away from the page, see `af::worker` */
+use std::{cell::RefCell, rc::Rc};
+
+use af::{
+    encoding::Enconding,
+    labellings::Labellings,
+    semantics::{Labelling, SEMANTICS_TYPES},
+    worker::{Request, Response},
+    AF,
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent};
+
+/** The framework and the labellings of the current job */
+struct Job {
+    id: u32,
+    framework: AF,
+    semantics: usize,
+    /* labellings the page already has, excluded from the search */
+    known: Vec<Labelling>,
+    /* started only once more labellings are wanted than the page has */
+    labellings: Option<Labellings>,
+    found: usize,
+    /* why the search stopped early, if the solver failed */
+    failed: Option<String>,
+}
+
+fn respond(scope: &DedicatedWorkerGlobalScope, response: Response) {
+    let _ = scope.post_message(&JsValue::from_str(&response.to_json()));
+}
+
+impl Job {
+    /** Sends every labelling found, as soon as it's found, until there are `wanted` */
+    fn list(&mut self, scope: &DedicatedWorkerGlobalScope, wanted: usize) {
+        while self.found < wanted {
+            if let Some(error) = &self.failed {
+                let (job, error) = (self.id, error.clone());
+                respond(scope, Response::FAILED { job, error });
+                return;
+            }
+            let labellings = self.labellings.get_or_insert_with(|| {
+                let semantics = SEMANTICS_TYPES[self.semantics];
+                self.framework.labellings_excluding(semantics, &self.known)
+            });
+            match labellings.next() {
+                Some(Ok(labelling)) => {
+                    self.found += 1;
+                    respond(
+                        scope,
+                        Response::FOUND {
+                            job: self.id,
+                            labelling,
+                        },
+                    );
+                }
+                Some(Err(error)) => self.failed = Some(error),
+                None => {
+                    respond(scope, Response::EXHAUSTED { job: self.id });
+                    return;
+                }
+            }
+        }
+        respond(scope, Response::LISTED { job: self.id });
+    }
+}
+
+fn handle(scope: &DedicatedWorkerGlobalScope, current: &mut Option<Job>, request: Request) {
+    let job = request.job();
+    if let Request::SOLVE {
+        text,
+        semantics,
+        encoding,
+        known,
+        wanted,
+        ..
+    } = request
+    {
+        let framework = AF::from(Enconding::parse(text)).with_encoding(encoding);
+        let current = current.insert(Job {
+            id: job,
+            framework,
+            semantics,
+            found: known.len(),
+            known,
+            labellings: None,
+            failed: None,
+        });
+        current.list(scope, wanted);
+        return;
+    }
+    let Some(current) = current.as_mut().filter(|current| current.id == job) else {
+        respond(scope, Response::STALE { job });
+        return;
+    };
+    match request {
+        Request::MORE { wanted, .. } => current.list(scope, wanted),
+        Request::COUNT { .. } => {
+            match current.framework.count(SEMANTICS_TYPES[current.semantics]) {
+                Ok(count) => respond(scope, Response::COUNTED { job, count }),
+                Err(error) => respond(scope, Response::FAILED { job, error }),
+            }
+        }
+        Request::ACCEPTANCE { arg, semantics, .. } => {
+            let framework = &current.framework;
+            let results = semantics
+                .iter()
+                .filter(|_| arg < framework.num_of_args)
+                .map(|&s| {
+                    Ok((
+                        framework.credulous(arg, SEMANTICS_TYPES[s])?,
+                        framework.skeptical(arg, SEMANTICS_TYPES[s])?,
+                    ))
+                })
+                .collect::<Result<_, String>>();
+            match results {
+                Ok(results) => respond(scope, Response::ACCEPTED { job, arg, results }),
+                Err(error) => respond(scope, Response::FAILED { job, error }),
+            }
+        }
+        Request::SOLVE { .. } => {}
+    }
+}
+
+fn main() {
+    let scope = js_sys::global().unchecked_into::<DedicatedWorkerGlobalScope>();
+    let current = Rc::new(RefCell::new(None::<Job>));
+    let onmessage = {
+        let scope = scope.clone();
+        Closure::<dyn Fn(MessageEvent)>::new(move |event: MessageEvent| {
+            let request = event
+                .data()
+                .as_string()
+                .and_then(|json| Request::from_json(&json));
+            if let Some(request) = request {
+                handle(&scope, &mut current.borrow_mut(), request);
+            }
+        })
+    };
+    scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    // the handler lives as long as the worker
+    onmessage.forget();
+}
@@ -0,0 +1,43 @@
+/*! A form of a framework that doesn't depend on how it was written, to recognise it again */
+use std::collections::BTreeSet;
+
+use crate::{Attack, AF};
+
+impl AF {
+    /** Indices of the arguments, ordered by name */
+    pub fn canonical_order(&self) -> Vec<usize> {
+        let names = self.arg_names();
+        let mut order = (0..self.num_of_args).collect::<Vec<usize>>();
+        order.sort_by(|&a, &b| names[a].cmp(&names[b]));
+        order
+    }
+
+    /** `arg` and `att` lines with arguments sorted by name and attacks sorted and without repetitions,
+     * the same for any order the framework was written in
+     */
+    pub fn canonical_text(&self) -> String {
+        let names = self.arg_names();
+        let attacks = self
+            .attacks
+            .iter()
+            .map(|Attack(origin, target)| (&names[*origin], &names[*target]))
+            .collect::<BTreeSet<_>>();
+        let mut text = String::new();
+        for i in self.canonical_order() {
+            text.push_str(&format!("arg({}).\n", names[i]));
+        }
+        for (origin, target) in attacks {
+            text.push_str(&format!("att({},{}).\n", origin, target));
+        }
+        text
+    }
+
+    /** FNV-1a hash of the canonical text, which doesn't change between builds or runs */
+    pub fn canonical_hash(&self) -> u64 {
+        self.canonical_text()
+            .bytes()
+            .fold(0xcbf29ce484222325, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            })
+    }
+}
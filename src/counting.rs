@@ -0,0 +1,23 @@
+use crate::{
+    semantics::SemanticsType,
+    AF,
+};
+
+impl AF {
+    /** Number of labellings of `semantics` (CE).
+     * Whenever labellings are the models of a formula they are counted one at a time by the SAT solver,
+     * and maximal ones are counted as they're found, without keeping any. Grounded, ideal and eager
+     * labellings are unique. It fails when the solver does.
+     */
+    pub fn count(&self, semantics: SemanticsType) -> Result<usize, String> {
+        match semantics {
+            SemanticsType::GROUNDED | SemanticsType::IDEAL | SemanticsType::EAGER => Ok(1),
+            _ => match self.labelling_formula(semantics) {
+                Some(formula) => self.sat.count(&formula.cnf),
+                None => self
+                    .labellings(semantics)
+                    .try_fold(0, |count, labelling| labelling.map(|_| count + 1)),
+            },
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*! The SAT encodings as DIMACS CNF, to be read by people or by other solvers */
+use crate::{
+    sat::{self, FormulaEncoding},
+    semantics::SemanticsType,
+    AF,
+};
+
+impl AF {
+    /** The formula whose models are the labellings of `semantics` in DIMACS CNF,
+     * as in Definition 5 of https://arxiv.org/pdf/1310.4986.pdf or in the extension encoding.
+     * Comments before the clauses name the variable of each label of each argument, e.g. `c 1 in_a`,
+     * `defeated_a` standing for `out_a` in the extension encoding.
+     * Only conflict-free, admissible, complete and stable labellings are the models of a single formula.
+     */
+    pub fn to_dimacs(&self, semantics: SemanticsType) -> Option<String> {
+        let formula = self.labelling_formula(semantics)?;
+        let names = self.arg_names();
+        let semantics: String = semantics.into();
+        let (encoding, out) = match self.encoding() {
+            FormulaEncoding::LABELLING => {
+                ("Definition 5 of https://arxiv.org/pdf/1310.4986.pdf", "out")
+            }
+            FormulaEncoding::EXTENSION => ("extension encoding", "defeated"),
+        };
+        let mut text = format!(
+            "c {} labellings of {} arguments, {}\n",
+            semantics, self.num_of_args, encoding
+        );
+        let vars = &formula.vars;
+        for (label, vars) in [("in", &vars.i), (out, &vars.o), ("undec", &vars.u)] {
+            for (var, name) in vars.iter().zip(&names) {
+                text.push_str(&format!("c {} {}_{}\n", var.to_dimacs(), label, name));
+            }
+        }
+        text.push_str(&sat::dimacs(&formula.cnf));
+        Some(text)
+    }
+}
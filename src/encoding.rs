@@ -0,0 +1,486 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+mod aif;
+mod dot;
+mod graphml;
+
+pub enum Enconding {
+    /* SIMPLE(labels, attacks)
+
+      Example:
+
+      arg(x).
+      arg(y).
+      att(x, y).
+      arg("z, (or w)").
+
+      SIMPLE(vec!["x", "y", "z, (or w)"], vec![("x", "y")])
+
+      Names with commas or parentheses are quoted, where \" and \\ stand for " and \.
+      Comments start with %, as in ASP, or with #
+    */
+    SIMPLE(Vec<String>, Vec<(String, String)>),
+    /* ICCMA(num_of_args, attacks)
+
+      ICCMA 2023 format, arguments are numbered from 1 to num_of_args
+
+      Example:
+
+      p af 2
+      # comment
+      1 2
+
+      ICCMA(2, vec![(1, 2)])
+    */
+    ICCMA(usize, Vec<(usize, usize)>),
+    /* TGF(labels, attacks)
+
+      Trivial Graph Format, a node is named by the rest of its line, or by its id when there's nothing else
+
+      Example:
+
+      1 Taxes are high
+      y
+      #
+      1 y
+
+      TGF(vec!["Taxes are high", "y"], vec![("Taxes are high", "y")])
+    */
+    TGF(Vec<String>, Vec<(String, String)>),
+    /* DOT(labels, attacks)
+
+      Graphviz digraph, only node and edge statements are kept
+
+      Example:
+
+      digraph {
+        x [fillcolor="green"];
+        x -> y;
+      }
+
+      DOT(vec!["x", "y"], vec![("x", "y")])
+    */
+    DOT(Vec<String>, Vec<(String, String)>),
+    /* AIF(labels, attacks)
+
+      AIF JSON argument map, I-nodes are arguments and CA nodes are attacks
+
+      Example:
+
+      {
+        "nodes": [
+          {"nodeID": "1", "text": "x", "type": "I"},
+          {"nodeID": "2", "text": "y", "type": "I"},
+          {"nodeID": "3", "text": "Default Conflict", "type": "CA"}
+        ],
+        "edges": [
+          {"edgeID": "1", "fromID": "1", "toID": "3"},
+          {"edgeID": "2", "fromID": "3", "toID": "2"}
+        ]
+      }
+
+      AIF(vec!["x", "y"], vec![("x", "y")])
+    */
+    AIF(Vec<String>, Vec<(String, String)>),
+    /* GRAPHML(labels, attacks)
+
+      GraphML graph, node ids are arguments and directed edges are attacks
+
+      Example:
+
+      <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+        <graph edgedefault="directed">
+          <node id="x"/>
+          <node id="y"/>
+          <edge source="x" target="y"/>
+        </graph>
+      </graphml>
+
+      GRAPHML(vec!["x", "y"], vec![("x", "y")])
+    */
+    GRAPHML(Vec<String>, Vec<(String, String)>),
+    ERROR(Vec<ParseError>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /* a predicate other than arg and att */
+    PREDICATE(String),
+    /* an attack mentions an argument that is never declared */
+    UNDECLARED(String),
+    /* an argument is declared twice */
+    DUPLICATE(String),
+    /* a predicate with the wrong number of arguments, e.g. att(a). */
+    ARITY(String, usize),
+    /* a statement not ending with a period */
+    PERIOD,
+    /* anything else, e.g. a missing parenthesis */
+    SYNTAX(String),
+}
+
+/** An error in the line and column (both starting at 1) of the parsed text */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::PREDICATE(pred) => write!(f, "unknown predicate \"{}\"", pred),
+            ParseErrorKind::UNDECLARED(arg) => write!(f, "argument {} is not declared", arg),
+            ParseErrorKind::DUPLICATE(arg) => write!(f, "argument {} is declared twice", arg),
+            ParseErrorKind::ARITY(pred, found) => {
+                let expected = if pred == "att" { 2 } else { 1 };
+                write!(
+                    f,
+                    "{} takes {} argument(s) but {} were given",
+                    pred, expected, found
+                )
+            }
+            ParseErrorKind::PERIOD => write!(f, "missing trailing period"),
+            ParseErrorKind::SYNTAX(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+/** Column (starting at 1) of the byte offset `at` in `line` */
+fn column(line: &str, at: usize) -> usize {
+    line[..at].chars().count() + 1
+}
+
+/** Byte offset of the first non-whitespace character of `line` from `at` */
+fn skip_whitespace(line: &str, at: usize) -> usize {
+    match line[at..].find(|c: char| !c.is_whitespace()) {
+        Some(offset) => at + offset,
+        None => line.len(),
+    }
+}
+
+/** Comma separated arguments of the statement whose `(` is at byte offset `start` of `line`, each with
+ * its byte offset, and the offset of the closing `)`. Quoted arguments may hold commas and parentheses.
+ * `None` when the statement isn't closed.
+ */
+fn statement_arguments(line: &str, start: usize) -> Option<(Vec<(String, usize)>, usize)> {
+    let mut parts = vec![];
+    let mut part = String::new();
+    let mut part_at = None;
+    let (mut quoted, mut was_quoted) = (false, false);
+    let mut chars = line[start + 1..]
+        .char_indices()
+        .map(|(i, c)| (start + 1 + i, c));
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if quoted => part.push(chars.next()?.1),
+            '"' => {
+                if !was_quoted {
+                    part = part.trim().to_owned();
+                }
+                quoted = !quoted;
+                was_quoted = true;
+                part_at.get_or_insert(i);
+            }
+            ',' | ')' if !quoted => {
+                // whitespace around an unquoted name isn't part of it
+                let name = match was_quoted {
+                    true => part.clone(),
+                    false => part.trim().to_owned(),
+                };
+                parts.push((name, part_at.unwrap_or(i)));
+                if c == ')' {
+                    return Some((parts, i));
+                }
+                part.clear();
+                part_at = None;
+                was_quoted = false;
+            }
+            _ if quoted => part.push(c),
+            _ if was_quoted && c.is_whitespace() => {}
+            _ => {
+                part.push(c);
+                if !c.is_whitespace() {
+                    part_at.get_or_insert(i);
+                }
+            }
+        }
+    }
+    None
+}
+
+/** `name` as an argument of an `arg` or `att` statement, quoted when it has to be */
+pub(crate) fn statement_name(name: &str) -> String {
+    let plain = !name.is_empty() && name.trim() == name && !name.contains([',', '(', ')', '"']);
+    match plain {
+        true => name.to_owned(),
+        false => format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\"")),
+    }
+}
+
+impl Enconding {
+    /** Parses `text` in whichever format it's written, detected from its content */
+    pub fn parse(text: String) -> Enconding {
+        let first = text.lines().map(|line| line.trim()).find(|line| {
+            !line.is_empty()
+                && !line.starts_with('#')
+                && !line.starts_with('%')
+                && !line.starts_with("//")
+        });
+        let is_statement = |line: &str| {
+            ["arg", "att"].iter().any(|predicate| {
+                line.strip_prefix(predicate)
+                    .is_some_and(|rest| rest.trim_start().starts_with('('))
+            })
+        };
+        let is_tgf =
+            text.lines().any(|line| line.trim() == "#") && !first.is_some_and(is_statement);
+        match first {
+            Some(line) if line.starts_with('{') => Enconding::parse_aif(text),
+            Some(line) if line.starts_with('<') => Enconding::parse_graphml(text),
+            Some(line) if line.starts_with("p ") => Enconding::parse_iccma(text),
+            Some(line) if line.starts_with("digraph") || line.starts_with("strict") => {
+                Enconding::parse_dot(text)
+            }
+            _ if is_tgf => Enconding::parse_tgf(text),
+            _ => Enconding::parse_simple(text),
+        }
+    }
+
+    pub fn parse_simple(text: String) -> Enconding {
+        let mut labels = vec![];
+        let mut declared = HashSet::new();
+        // attacks with the position of both arguments, checked once every argument is declared
+        let mut attacks = vec![];
+        let mut errors = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let error = |at: usize, kind: ParseErrorKind| ParseError {
+                line: i + 1,
+                column: column(line, at),
+                kind,
+            };
+            if line.trim().is_empty() || line.trim_start().starts_with(['#', '%']) {
+                continue;
+            }
+            // a line may hold several statements
+            let mut at = skip_whitespace(line, 0);
+            // up to a comment after the last statement
+            while at < line.len() && !line[at..].starts_with('%') {
+                let Some(start) = line[at..].find('(').map(|offset| at + offset) else {
+                    errors.push(error(
+                        at,
+                        ParseErrorKind::PREDICATE(line[at..].trim().to_owned()),
+                    ));
+                    break;
+                };
+                let Some((parts, end)) = statement_arguments(line, start) else {
+                    errors.push(error(
+                        line.len(),
+                        ParseErrorKind::SYNTAX(String::from("missing )")),
+                    ));
+                    break;
+                };
+                let before = line[at..start].trim();
+                match before {
+                    "arg" => match &parts[..] {
+                        [(label, label_at)] if !label.is_empty() => {
+                            if declared.insert(label.to_owned()) {
+                                labels.push(label.to_owned());
+                            } else {
+                                errors.push(error(
+                                    *label_at,
+                                    ParseErrorKind::DUPLICATE(label.to_owned()),
+                                ));
+                            }
+                        }
+                        _ => errors.push(error(
+                            start,
+                            ParseErrorKind::ARITY(String::from("arg"), parts.len()),
+                        )),
+                    },
+                    "att" => match &parts[..] {
+                        [(origin, origin_at), (target, target_at)] => {
+                            attacks.push((
+                                origin.to_owned(),
+                                error(*origin_at, ParseErrorKind::UNDECLARED(origin.to_owned())),
+                                target.to_owned(),
+                                error(*target_at, ParseErrorKind::UNDECLARED(target.to_owned())),
+                            ));
+                        }
+                        _ => errors.push(error(
+                            start,
+                            ParseErrorKind::ARITY(String::from("att"), parts.len()),
+                        )),
+                    },
+                    _ => errors.push(error(at, ParseErrorKind::PREDICATE(before.to_owned()))),
+                }
+                at = skip_whitespace(line, end + 1);
+                if line[at..].starts_with('.') {
+                    at = skip_whitespace(line, at + 1);
+                } else {
+                    errors.push(error(end + 1, ParseErrorKind::PERIOD));
+                }
+            }
+        }
+        let mut checked = vec![];
+        for (origin, origin_error, target, target_error) in attacks {
+            if !declared.contains(&origin) {
+                errors.push(origin_error);
+            }
+            if !declared.contains(&target) {
+                errors.push(target_error);
+            }
+            checked.push((origin, target));
+        }
+        if !errors.is_empty() {
+            errors.sort_by_key(|e| (e.line, e.column));
+            return Enconding::ERROR(errors);
+        }
+        Enconding::SIMPLE(labels, checked)
+    }
+
+    pub fn parse_iccma(text: String) -> Enconding {
+        let mut num_of_args = None;
+        let mut attacks = vec![];
+        let mut errors = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let error = |at: usize, kind: ParseErrorKind| ParseError {
+                line: i + 1,
+                column: column(line, at),
+                kind,
+            };
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let parts = line
+                .split_whitespace()
+                .map(|part| (part, part.as_ptr() as usize - line.as_ptr() as usize))
+                .collect::<Vec<_>>();
+            match (num_of_args, &parts[..]) {
+                (None, &[("p", _), ("af", _), (n, n_at)]) => match n.parse::<usize>() {
+                    Ok(n) => num_of_args = Some(n),
+                    Err(_) => errors.push(error(
+                        n_at,
+                        ParseErrorKind::SYNTAX(format!("invalid number of arguments {}", n)),
+                    )),
+                },
+                (None, _) => {
+                    errors.push(error(
+                        0,
+                        ParseErrorKind::SYNTAX(String::from("missing p af header")),
+                    ));
+                    break;
+                }
+                (Some(n), &[(origin, origin_at), (target, target_at)]) => {
+                    let mut attack = vec![];
+                    for (arg, arg_at) in [(origin, origin_at), (target, target_at)] {
+                        match arg.parse::<usize>() {
+                            Ok(arg) if (1..=n).contains(&arg) => attack.push(arg),
+                            Ok(_) => errors
+                                .push(error(arg_at, ParseErrorKind::UNDECLARED(arg.to_owned()))),
+                            Err(_) => errors.push(error(
+                                arg_at,
+                                ParseErrorKind::SYNTAX(format!("{} is not a number", arg)),
+                            )),
+                        }
+                    }
+                    if let [origin, target] = attack[..] {
+                        attacks.push((origin, target));
+                    }
+                }
+                (Some(_), _) => errors.push(error(
+                    0,
+                    ParseErrorKind::SYNTAX(String::from("an attack needs an origin and a target")),
+                )),
+            }
+        }
+        if num_of_args.is_none() && errors.is_empty() {
+            errors.push(ParseError {
+                line: 1,
+                column: 1,
+                kind: ParseErrorKind::SYNTAX(String::from("missing p af header")),
+            });
+        }
+        match num_of_args {
+            Some(n) if errors.is_empty() => Enconding::ICCMA(n, attacks),
+            _ => Enconding::ERROR(errors),
+        }
+    }
+
+    pub fn parse_tgf(text: String) -> Enconding {
+        let mut labels = vec![];
+        let mut declared = HashSet::new();
+        // the argument name of every node id
+        let mut names = HashMap::new();
+        let mut attacks = vec![];
+        let mut errors = vec![];
+        let mut edges = false;
+        for (i, line) in text.lines().enumerate() {
+            let error = |at: usize, kind: ParseErrorKind| ParseError {
+                line: i + 1,
+                column: column(line, at),
+                kind,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if line.trim() == "#" {
+                edges = true;
+                continue;
+            }
+            let parts = line
+                .split_whitespace()
+                .map(|part| (part, part.as_ptr() as usize - line.as_ptr() as usize))
+                .collect::<Vec<_>>();
+            if !edges {
+                let (id, id_at) = parts[0];
+                let label_at = skip_whitespace(line, id_at + id.len());
+                let label = match line[label_at..].trim_end() {
+                    "" => (id, id_at),
+                    label => (label, label_at),
+                };
+                if names.contains_key(id) {
+                    errors.push(error(id_at, ParseErrorKind::DUPLICATE(id.to_owned())));
+                } else if !declared.insert(label.0.to_owned()) {
+                    errors.push(error(
+                        label.1,
+                        ParseErrorKind::DUPLICATE(label.0.to_owned()),
+                    ));
+                } else {
+                    names.insert(id.to_owned(), label.0.to_owned());
+                    labels.push(label.0.to_owned());
+                }
+                continue;
+            }
+            match parts[..] {
+                [(origin, origin_at), (target, target_at), ..] => {
+                    for (arg, arg_at) in [(origin, origin_at), (target, target_at)] {
+                        if !names.contains_key(arg) {
+                            errors.push(error(arg_at, ParseErrorKind::UNDECLARED(arg.to_owned())));
+                        }
+                    }
+                    if let (Some(origin), Some(target)) = (names.get(origin), names.get(target)) {
+                        attacks.push((origin.to_owned(), target.to_owned()));
+                    }
+                }
+                _ => errors.push(error(
+                    0,
+                    ParseErrorKind::SYNTAX(String::from("an edge needs an origin and a target")),
+                )),
+            }
+        }
+        if !errors.is_empty() {
+            return Enconding::ERROR(errors);
+        }
+        Enconding::TGF(labels, attacks)
+    }
+}
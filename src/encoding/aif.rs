@@ -0,0 +1,158 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{json, Value};
+
+use super::{Enconding, ParseError, ParseErrorKind};
+use crate::{semantics::Labelling, Attack, AF};
+
+fn syntax_error(message: &str) -> Enconding {
+    Enconding::ERROR(vec![ParseError {
+        line: 1,
+        column: 1,
+        kind: ParseErrorKind::SYNTAX(String::from(message)),
+    }])
+}
+
+/** Node and edge ids may be written either as strings or as numbers */
+fn id_of(value: Option<&Value>) -> Option<String> {
+    match value {
+        Some(Value::String(id)) => Some(id.clone()),
+        Some(Value::Number(id)) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+impl Enconding {
+    /** Parses an AIF JSON argument map, as produced by OVA or AIFdb.
+     * Every I-node is an argument named after its text, and every CA node attacks the I-nodes it points
+     * to from the I-nodes pointing to it. A CA node pointing to an RA node attacks the conclusion of that
+     * inference.
+     */
+    pub fn parse_aif(text: String) -> Enconding {
+        let json: Value = match serde_json::from_str(&text) {
+            Ok(json) => json,
+            Err(error) => {
+                return Enconding::ERROR(vec![ParseError {
+                    line: error.line(),
+                    column: error.column(),
+                    kind: ParseErrorKind::SYNTAX(format!("invalid JSON, {}", error)),
+                }])
+            }
+        };
+        let (Some(nodes), Some(edges)) = (json["nodes"].as_array(), json["edges"].as_array())
+        else {
+            return syntax_error("an AIF map needs nodes and edges");
+        };
+
+        let mut kind_by_id = HashMap::new();
+        let mut labels = vec![];
+        let mut label_by_id = HashMap::new();
+        let mut used = HashSet::new();
+        let mut conflicts = vec![];
+        for node in nodes {
+            let (Some(id), Some(kind)) = (id_of(node.get("nodeID")), node["type"].as_str()) else {
+                return syntax_error("every node needs a nodeID and a type");
+            };
+            if kind == "I" {
+                let text = node["text"].as_str().unwrap_or("").trim().to_owned();
+                // argument names must be unique
+                let label = match used.contains(&text) || text.is_empty() {
+                    true => format!("{} ({})", text, id).trim().to_owned(),
+                    false => text,
+                };
+                used.insert(label.clone());
+                labels.push(label.clone());
+                label_by_id.insert(id.clone(), label);
+            }
+            if kind == "CA" {
+                conflicts.push(id.clone());
+            }
+            kind_by_id.insert(id, kind.to_owned());
+        }
+
+        let mut incoming: HashMap<String, Vec<String>> = HashMap::new();
+        let mut outgoing: HashMap<String, Vec<String>> = HashMap::new();
+        for edge in edges {
+            let (Some(from), Some(to)) = (id_of(edge.get("fromID")), id_of(edge.get("toID")))
+            else {
+                return syntax_error("every edge needs a fromID and a toID");
+            };
+            outgoing.entry(from.clone()).or_default().push(to.clone());
+            incoming.entry(to).or_default().push(from);
+        }
+        let empty = vec![];
+        // I-nodes standing for `id`: itself, or the conclusions of an inference
+        let arguments = |id: &String| -> Vec<String> {
+            match kind_by_id.get(id).map(|kind| kind.as_str()) {
+                Some("I") => vec![label_by_id[id].clone()],
+                Some("RA") => outgoing
+                    .get(id)
+                    .unwrap_or(&empty)
+                    .iter()
+                    .filter_map(|to| label_by_id.get(to).cloned())
+                    .collect(),
+                _ => vec![],
+            }
+        };
+
+        let mut attacks = vec![];
+        for id in &conflicts {
+            for from in incoming.get(id).unwrap_or(&empty) {
+                for to in outgoing.get(id).unwrap_or(&empty) {
+                    for origin in arguments(from) {
+                        for target in arguments(to) {
+                            attacks.push((origin.clone(), target));
+                        }
+                    }
+                }
+            }
+        }
+        Enconding::AIF(labels, attacks)
+    }
+}
+
+impl AF {
+    /** Writes the framework as an AIF JSON argument map, with one CA node for each attack.
+     * The label of every argument in `labelling` is kept in the "acceptability" field of its I-node.
+     */
+    pub fn to_aif(&self, labelling: Option<&Labelling>) -> String {
+        let names = self.arg_names();
+        let mut nodes = vec![];
+        let mut edges = vec![];
+        for (i, name) in names.iter().enumerate() {
+            let mut node = json!({
+                "nodeID": (i + 1).to_string(),
+                "text": name,
+                "type": "I",
+            });
+            if let Some(acc) = labelling.and_then(|labelling| labelling.0.get(i)) {
+                node["acceptability"] = json!(format!("{:?}", acc));
+            }
+            nodes.push(node);
+        }
+        for (k, Attack(origin, target)) in self.attacks.iter().enumerate() {
+            let conflict_id = (names.len() + k + 1).to_string();
+            nodes.push(json!({
+                "nodeID": conflict_id,
+                "text": "Default Conflict",
+                "type": "CA",
+            }));
+            edges.push(json!({
+                "edgeID": (2 * k + 1).to_string(),
+                "fromID": (origin + 1).to_string(),
+                "toID": conflict_id,
+            }));
+            edges.push(json!({
+                "edgeID": (2 * k + 2).to_string(),
+                "fromID": conflict_id,
+                "toID": (target + 1).to_string(),
+            }));
+        }
+        let aif = json!({
+            "nodes": nodes,
+            "edges": edges,
+            "locutions": [],
+        });
+        serde_json::to_string_pretty(&aif).unwrap_or_default()
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use super::{Enconding, ParseError, ParseErrorKind};
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    ID(String),
+    SYMBOL(&'static str),
+}
+
+/** A token with its line and column */
+struct Positioned(Token, usize, usize);
+
+fn tokenize(text: &str) -> Result<Vec<Positioned>, ParseError> {
+    let mut tokens = vec![];
+    let chars = text.chars().collect::<Vec<char>>();
+    let (mut line, mut column) = (1, 1);
+    let mut i = 0;
+    let advance = |i: &mut usize, line: &mut usize, column: &mut usize| {
+        if chars[*i] == '\n' {
+            *line += 1;
+            *column = 1;
+        } else {
+            *column += 1;
+        }
+        *i += 1;
+    };
+    while i < chars.len() {
+        let (start_line, start_column) = (line, column);
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            advance(&mut i, &mut line, &mut column);
+        } else if c == '#' || (c == '/' && next == Some('/')) {
+            while i < chars.len() && chars[i] != '\n' {
+                advance(&mut i, &mut line, &mut column);
+            }
+        } else if c == '/' && next == Some('*') {
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                advance(&mut i, &mut line, &mut column);
+            }
+            if i < chars.len() {
+                advance(&mut i, &mut line, &mut column);
+                advance(&mut i, &mut line, &mut column);
+            }
+        } else if c == '-' && (next == Some('>') || next == Some('-')) {
+            let symbol = if next == Some('>') { "->" } else { "--" };
+            advance(&mut i, &mut line, &mut column);
+            advance(&mut i, &mut line, &mut column);
+            tokens.push(Positioned(Token::SYMBOL(symbol), start_line, start_column));
+        } else if let Some(symbol) = ["{", "}", "[", "]", ";", ",", "=", ":"]
+            .into_iter()
+            .find(|s| s.starts_with(c))
+        {
+            advance(&mut i, &mut line, &mut column);
+            tokens.push(Positioned(Token::SYMBOL(symbol), start_line, start_column));
+        } else if c == '"' {
+            let mut id = String::new();
+            advance(&mut i, &mut line, &mut column);
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' && chars.get(i + 1) == Some(&'"') {
+                    advance(&mut i, &mut line, &mut column);
+                }
+                id.push(chars[i]);
+                advance(&mut i, &mut line, &mut column);
+            }
+            if i == chars.len() {
+                return Err(ParseError {
+                    line: start_line,
+                    column: start_column,
+                    kind: ParseErrorKind::SYNTAX(String::from("unterminated string")),
+                });
+            }
+            advance(&mut i, &mut line, &mut column);
+            tokens.push(Positioned(Token::ID(id), start_line, start_column));
+        } else if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' {
+            let mut id = String::new();
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                id.push(chars[i]);
+                advance(&mut i, &mut line, &mut column);
+            }
+            if id.is_empty() {
+                // a lone '-'
+                id.push(c);
+                advance(&mut i, &mut line, &mut column);
+            }
+            tokens.push(Positioned(Token::ID(id), start_line, start_column));
+        } else {
+            return Err(ParseError {
+                line,
+                column,
+                kind: ParseErrorKind::SYNTAX(format!("unexpected character {}", c)),
+            });
+        }
+    }
+    Ok(tokens)
+}
+
+fn is_keyword(token: Option<&Positioned>, keyword: &str) -> bool {
+    match token {
+        Some(Positioned(Token::ID(id), _, _)) => id.eq_ignore_ascii_case(keyword),
+        _ => false,
+    }
+}
+
+fn is_symbol(token: Option<&Positioned>, symbol: &str) -> bool {
+    match token {
+        Some(Positioned(Token::SYMBOL(s), _, _)) => *s == symbol,
+        _ => false,
+    }
+}
+
+impl Enconding {
+    /** Parses a DOT digraph, keeping only its node and edge statements.
+     * Node names are their ids, and nodes mentioned only in edges are declared implicitly.
+     */
+    pub fn parse_dot(text: String) -> Enconding {
+        let tokens = match tokenize(&text) {
+            Ok(tokens) => tokens,
+            Err(error) => return Enconding::ERROR(vec![error]),
+        };
+        let error = |at: Option<&Positioned>, message: &str| {
+            let (line, column) = match at {
+                Some(Positioned(_, line, column)) => (*line, *column),
+                None => (text.lines().count().max(1), 1),
+            };
+            Enconding::ERROR(vec![ParseError {
+                line,
+                column,
+                kind: ParseErrorKind::SYNTAX(String::from(message)),
+            }])
+        };
+
+        let mut i = 0;
+        if is_keyword(tokens.get(i), "strict") {
+            i += 1;
+        }
+        if !is_keyword(tokens.get(i), "digraph") {
+            return error(tokens.get(i), "expected digraph");
+        }
+        i += 1;
+        if let Some(Positioned(Token::ID(_), _, _)) = tokens.get(i) {
+            i += 1;
+        }
+        if !is_symbol(tokens.get(i), "{") {
+            return error(tokens.get(i), "expected {");
+        }
+        i += 1;
+
+        let mut labels = vec![];
+        let mut declared = HashSet::new();
+        let mut attacks = vec![];
+        let mut declare = |id: &String, labels: &mut Vec<String>| {
+            if declared.insert(id.clone()) {
+                labels.push(id.clone());
+            }
+        };
+        loop {
+            match tokens.get(i) {
+                None => return error(None, "expected }"),
+                Some(Positioned(Token::SYMBOL("}"), _, _)) => break,
+                Some(Positioned(Token::SYMBOL(";"), _, _)) => {
+                    i += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            if is_keyword(tokens.get(i), "subgraph") || is_symbol(tokens.get(i), "{") {
+                return error(tokens.get(i), "only node and edge statements are supported");
+            }
+            let attribute_statement = ["graph", "node", "edge"]
+                .iter()
+                .any(|keyword| is_keyword(tokens.get(i), keyword))
+                && is_symbol(tokens.get(i + 1), "[");
+            let Some(Positioned(Token::ID(id), _, _)) = tokens.get(i) else {
+                return error(tokens.get(i), "expected a node id");
+            };
+            i += 1;
+            if attribute_statement {
+                // graph, node or edge defaults, nothing to keep
+            } else if is_symbol(tokens.get(i), "=") {
+                // graph attribute, e.g. rankdir=LR
+                i += 2;
+                continue;
+            } else {
+                let mut chain = vec![id];
+                loop {
+                    // ports, e.g. a:n, are ignored
+                    while is_symbol(tokens.get(i), ":") {
+                        i += 2;
+                    }
+                    if is_symbol(tokens.get(i), "--") {
+                        return error(tokens.get(i), "undirected edges are not attacks");
+                    }
+                    if !is_symbol(tokens.get(i), "->") {
+                        break;
+                    }
+                    i += 1;
+                    match tokens.get(i) {
+                        Some(Positioned(Token::ID(target), _, _)) => chain.push(target),
+                        other => return error(other, "expected a node id"),
+                    }
+                    i += 1;
+                }
+                for id in &chain {
+                    declare(id, &mut labels);
+                }
+                for pair in chain.windows(2) {
+                    attacks.push((pair[0].clone(), pair[1].clone()));
+                }
+            }
+            // attribute lists are ignored
+            while is_symbol(tokens.get(i), "[") {
+                while !is_symbol(tokens.get(i), "]") {
+                    if tokens.get(i).is_none() {
+                        return error(None, "expected ]");
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+        }
+        Enconding::DOT(labels, attacks)
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+
+use roxmltree::{Document, Node};
+
+use super::{Enconding, ParseError, ParseErrorKind};
+use crate::{semantics::Labelling, Attack, AF};
+
+fn error_at(document: &Document, node: Node, kind: ParseErrorKind) -> ParseError {
+    let position = document.text_pos_at(node.range().start);
+    ParseError {
+        line: position.row as usize,
+        column: position.col as usize,
+        kind,
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Enconding {
+    /** Parses a GraphML graph, as written by Gephi or yEd.
+     * Node ids are the argument names and every directed edge is an attack. Data attributes are ignored.
+     */
+    pub fn parse_graphml(text: String) -> Enconding {
+        let document = match Document::parse(&text) {
+            Ok(document) => document,
+            Err(error) => {
+                let position = error.pos();
+                return Enconding::ERROR(vec![ParseError {
+                    line: position.row as usize,
+                    column: position.col as usize,
+                    kind: ParseErrorKind::SYNTAX(format!("invalid XML, {}", error)),
+                }]);
+            }
+        };
+        let Some(graph) = document
+            .descendants()
+            .find(|node| node.has_tag_name("graph"))
+        else {
+            return Enconding::ERROR(vec![error_at(
+                &document,
+                document.root_element(),
+                ParseErrorKind::SYNTAX(String::from("expected a graph element")),
+            )]);
+        };
+        let undirected_by_default = graph.attribute("edgedefault") == Some("undirected");
+
+        let mut labels = vec![];
+        let mut declared = HashSet::new();
+        let mut errors = vec![];
+        for node in graph.children().filter(|node| node.has_tag_name("node")) {
+            let Some(id) = node.attribute("id") else {
+                errors.push(error_at(
+                    &document,
+                    node,
+                    ParseErrorKind::SYNTAX(String::from("a node needs an id")),
+                ));
+                continue;
+            };
+            if !declared.insert(id) {
+                errors.push(error_at(
+                    &document,
+                    node,
+                    ParseErrorKind::DUPLICATE(id.to_owned()),
+                ));
+                continue;
+            }
+            labels.push(id.to_owned());
+        }
+
+        let mut attacks = vec![];
+        for edge in graph.children().filter(|node| node.has_tag_name("edge")) {
+            let directed = match edge.attribute("directed") {
+                Some(directed) => directed == "true",
+                None => !undirected_by_default,
+            };
+            if !directed {
+                errors.push(error_at(
+                    &document,
+                    edge,
+                    ParseErrorKind::SYNTAX(String::from("undirected edges are not attacks")),
+                ));
+                continue;
+            }
+            let (Some(source), Some(target)) = (edge.attribute("source"), edge.attribute("target"))
+            else {
+                errors.push(error_at(
+                    &document,
+                    edge,
+                    ParseErrorKind::SYNTAX(String::from("an edge needs a source and a target")),
+                ));
+                continue;
+            };
+            for id in [source, target] {
+                if !declared.contains(id) {
+                    errors.push(error_at(
+                        &document,
+                        edge,
+                        ParseErrorKind::UNDECLARED(id.to_owned()),
+                    ));
+                }
+            }
+            attacks.push((source.to_owned(), target.to_owned()));
+        }
+
+        if !errors.is_empty() {
+            return Enconding::ERROR(errors);
+        }
+        Enconding::GRAPHML(labels, attacks)
+    }
+}
+
+impl AF {
+    /** Writes the framework as a directed GraphML graph whose node ids are the argument names.
+     * Every labelling in `labellings` becomes a node attribute "extension_k" holding IN, OUT or UNDEC.
+     */
+    pub fn to_graphml(&self, labellings: &[Labelling]) -> String {
+        let names = self.arg_names();
+        let mut graphml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        graphml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for k in 0..labellings.len() {
+            graphml.push_str(&format!(
+                "  <key id=\"ext{}\" for=\"node\" attr.name=\"extension_{}\" attr.type=\"string\"/>\n",
+                k,
+                k + 1
+            ));
+        }
+        graphml.push_str("  <graph id=\"af\" edgedefault=\"directed\">\n");
+        for (i, name) in names.iter().enumerate() {
+            let data = labellings
+                .iter()
+                .enumerate()
+                .filter_map(|(k, labelling)| labelling.0.get(i).map(|acc| (k, acc)))
+                .map(|(k, acc)| format!("      <data key=\"ext{}\">{:?}</data>\n", k, acc))
+                .collect::<String>();
+            match data.is_empty() {
+                true => graphml.push_str(&format!("    <node id=\"{}\"/>\n", escape(name))),
+                false => graphml.push_str(&format!(
+                    "    <node id=\"{}\">\n{}    </node>\n",
+                    escape(name),
+                    data
+                )),
+            }
+        }
+        for Attack(origin, target) in &self.attacks {
+            graphml.push_str(&format!(
+                "    <edge source=\"{}\" target=\"{}\"/>\n",
+                escape(&names[*origin]),
+                escape(&names[*target])
+            ));
+        }
+        graphml.push_str("  </graph>\n</graphml>\n");
+        graphml
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    semantics::{Acceptability, Labelling},
+    Attack, AF,
+};
+
+pub trait DotDrawable {
+    /** Graphviz digraph of the framework, with nodes filled by the colours of `labelling` */
+    fn to_dot(&self, labelling: Option<&Labelling>) -> String;
+}
+
+pub fn color_by_acceptability(acc: &Acceptability) -> String {
+    match acc {
+        Acceptability::IN => String::from("green"),
+        Acceptability::OUT => String::from("red"),
+        Acceptability::UNDEC => String::from("blue"),
+    }
+}
+
+/** Quoted DOT id, where only quotes are escaped */
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\\\""))
+}
+
+impl DotDrawable for AF {
+    fn to_dot(&self, labelling: Option<&Labelling>) -> String {
+        let names = self.arg_names();
+        let mut dot = String::from("digraph af {\n");
+        if labelling.is_some() {
+            dot.push_str("    node [style=filled, fontcolor=white];\n");
+        }
+        for (i, name) in names.iter().enumerate() {
+            match labelling.and_then(|labelling| labelling.0.get(i)) {
+                Some(acc) => dot.push_str(&format!(
+                    "    {} [fillcolor={}];\n",
+                    dot_id(name),
+                    dot_id(&color_by_acceptability(acc))
+                )),
+                None => dot.push_str(&format!("    {};\n", dot_id(name))),
+            }
+        }
+        for Attack(origin, target) in &self.attacks {
+            dot.push_str(&format!(
+                "    {} -> {};\n",
+                dot_id(&names[*origin]),
+                dot_id(&names[*target])
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{
+    sat::{Backend, CnfFormulaExtension, ExtendFormula, Formula, Lit, Models},
+    semantics::{
+        Acceptability::{IN, OUT, UNDEC},
+        Labelling, SemanticsType,
+    },
+    AF,
+};
+
+enum Search {
+    /* every model of a formula */
+    MODELS(Models),
+    /* labellings with maximal IN sets, the ones found so far are excluded in the solver */
+    INMAXIMAL(Formula, Box<dyn Backend>),
+    /* labellings with minimal UNDEC sets, and the labellings of the last such set found */
+    RANGEMAXIMAL(Formula, Box<dyn Backend>, Option<Models>),
+    /* semantics that are only computed as a whole */
+    LISTED(std::vec::IntoIter<Labelling>),
+    /* a search the solver failed, with the failure until it's reported */
+    FAILED(Option<String>),
+}
+
+/** Labellings of a semantics, each one computed only when asked for.
+ * When the solver fails, the failure is the last item.
+ */
+pub struct Labellings {
+    af: AF,
+    search: Search,
+    found: bool,
+}
+
+impl AF {
+    /** Labellings of `semantics`, computed lazily whenever the semantics is given by SAT calls.
+     * Complete, admissible, conflict-free and stable labellings come from a single incremental solver.
+     */
+    pub fn labellings(&self, semantics: SemanticsType) -> Labellings {
+        self.labellings_excluding(semantics, &[])
+    }
+
+    /** Every labelling of `semantics`, or why the solver couldn't find them */
+    pub fn all_labellings(&self, semantics: SemanticsType) -> Result<Vec<Labelling>, String> {
+        self.labellings(semantics).collect()
+    }
+
+    /** Labellings of `semantics` other than `known`, which are the first ones listed by some earlier search.
+     * They're excluded by clauses given to the solver beforehand, so they're never found again.
+     */
+    pub fn labellings_excluding(
+        &self,
+        semantics: SemanticsType,
+        known: &[Labelling],
+    ) -> Labellings {
+        let mut formula = self.create_formula();
+        let search = match semantics {
+            SemanticsType::CONFLICTFREE => {
+                self.add_conflict_free_labelling_clauses(&mut formula);
+                self.models_excluding(formula, known)
+            }
+            SemanticsType::ADMISSIBLE => {
+                self.add_admissible_clauses(&mut formula);
+                self.models_excluding(formula, known)
+            }
+            SemanticsType::COMPLETE => {
+                self.add_complete_clauses(&mut formula);
+                self.models_excluding(formula, known)
+            }
+            SemanticsType::STABLE => {
+                self.add_stable_clauses(&mut formula);
+                self.models_excluding(formula, known)
+            }
+            SemanticsType::PREFERRED => {
+                self.add_complete_clauses(&mut formula);
+                self.add_not_empty_clause(&mut formula);
+                self.in_maximal_excluding(formula, known)
+            }
+            SemanticsType::NAIVE => {
+                self.add_conflict_free_labelling_clauses(&mut formula);
+                self.add_not_empty_clause(&mut formula);
+                self.in_maximal_excluding(formula, known)
+            }
+            SemanticsType::SEMISTABLE => {
+                self.add_complete_clauses(&mut formula);
+                self.add_undec_vars(&mut formula);
+                self.range_maximal_excluding(formula, known)
+            }
+            SemanticsType::STAGE => {
+                self.add_conflict_free_labelling_clauses(&mut formula);
+                self.add_undec_vars(&mut formula);
+                self.range_maximal_excluding(formula, known)
+            }
+            _ => match self.listed(semantics) {
+                Ok(labellings) => Search::LISTED(
+                    labellings
+                        .into_iter()
+                        .filter(|labelling| !known.contains(labelling))
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                ),
+                Err(error) => Search::FAILED(Some(error)),
+            },
+        };
+        Labellings {
+            af: self.clone(),
+            search,
+            found: !known.is_empty(),
+        }
+    }
+
+    /** A solver with the clauses of `formula`, kept through the whole search */
+    fn incremental(&self, formula: &Formula) -> Box<dyn Backend> {
+        let mut backend = self.sat.backend();
+        backend.add_formula(&formula.cnf);
+        backend
+    }
+
+    /** Clause excluding exactly `labelling` */
+    fn excluding_clause(&self, formula: &Formula, labelling: &Labelling) -> Vec<Lit> {
+        let (i, o) = (&formula.vars.i, &formula.vars.o);
+        labelling
+            .0
+            .iter()
+            .enumerate()
+            .flat_map(|(arg, label)| match label {
+                IN => vec![i[arg].negative()],
+                OUT => vec![o[arg].negative()],
+                UNDEC => vec![i[arg].positive(), o[arg].positive()],
+            })
+            .collect()
+    }
+
+    fn models_excluding(&self, mut formula: Formula, known: &[Labelling]) -> Search {
+        for labelling in known {
+            let clause = self.excluding_clause(&formula, labelling);
+            formula.cnf.add_clause(&clause);
+        }
+        Search::MODELS(self.sat.models(&formula.cnf))
+    }
+
+    /** Since known labellings are maximal, the same clause `next_in_maximal` adds excludes each of them */
+    fn in_maximal_excluding(&self, mut formula: Formula, known: &[Labelling]) -> Search {
+        for labelling in known {
+            let optimize_clause = (0..self.num_of_args)
+                .filter(|&arg| labelling.0[arg] != IN)
+                .map(|arg| formula.vars.i[arg].positive())
+                .collect::<Vec<Lit>>();
+            formula.cnf.add_clause(&optimize_clause);
+        }
+        let backend = self.incremental(&formula);
+        Search::INMAXIMAL(formula, backend)
+    }
+
+    /** Labellings are listed a whole UNDEC set after another, so only the set of the last known one may have
+     * labellings left, which are listed first
+     */
+    fn range_maximal_excluding(&self, mut formula: Formula, known: &[Labelling]) -> Search {
+        let undec = |labelling: &Labelling| {
+            (0..self.num_of_args)
+                .filter(|&arg| labelling.0[arg] == UNDEC)
+                .collect::<Vec<usize>>()
+        };
+        let same_range = known.last().map(|last| {
+            let range = undec(last);
+            let mut same_range = CnfFormulaExtension::clone(&formula.cnf);
+            for arg in 0..self.num_of_args {
+                let var = formula.vars.u[arg];
+                same_range.add_clause(&[var.lit(range.contains(&arg))]);
+            }
+            for labelling in known.iter().filter(|labelling| undec(labelling) == range) {
+                same_range.add_clause(&self.excluding_clause(&formula, labelling));
+            }
+            self.sat.models(&same_range)
+        });
+        let mut ranges = known.iter().map(undec).collect::<Vec<_>>();
+        ranges.dedup();
+        for range in ranges {
+            let optimize_clause = range
+                .iter()
+                .map(|&arg| formula.vars.u[arg].negative())
+                .collect::<Vec<Lit>>();
+            formula.cnf.add_clause(&optimize_clause);
+        }
+        let backend = self.incremental(&formula);
+        Search::RANGEMAXIMAL(formula, backend, same_range)
+    }
+}
+
+impl Iterator for Labellings {
+    type Item = Result<Labelling, String>;
+
+    fn next(&mut self) -> Option<Result<Labelling, String>> {
+        let af = &self.af;
+        let next = match &mut self.search {
+            Search::MODELS(models) => models
+                .next()
+                .transpose()
+                .map(|model| model.map(|model| af.label(&model))),
+            Search::INMAXIMAL(formula, backend) => af.next_in_maximal(formula, backend.as_mut()),
+            Search::RANGEMAXIMAL(formula, backend, same_range) => loop {
+                match same_range.as_mut().and_then(|models| models.next()) {
+                    Some(Ok(model)) => break Ok(Some(af.label(&model))),
+                    Some(Err(error)) => break Err(error),
+                    None => {}
+                }
+                match af.next_range_maximal(formula, backend.as_mut()) {
+                    Ok(Some(models)) => *same_range = Some(models),
+                    Ok(None) => break Ok(None),
+                    Err(error) => break Err(error),
+                }
+            },
+            Search::LISTED(labellings) => Ok(labellings.next()),
+            Search::FAILED(error) => error.take().map_or(Ok(None), Err),
+        };
+        match next {
+            Ok(Some(labelling)) => {
+                self.found = true;
+                Some(Ok(labelling))
+            }
+            Ok(None) => match &self.search {
+                // when the empty set is the only maximal one
+                Search::INMAXIMAL(..) | Search::RANGEMAXIMAL(..) if !self.found => {
+                    self.found = true;
+                    Some(Ok(Labelling(vec![UNDEC; af.num_of_args])))
+                }
+                _ => None,
+            },
+            Err(error) => {
+                self.search = Search::FAILED(None);
+                Some(Err(error))
+            }
+        }
+    }
+}
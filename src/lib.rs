@@ -0,0 +1,798 @@
+/*! Argumentation frameworks, their encodings and semantics, computed with a SAT solver.
+ * The browser front-end lives in the binaries and is built with the "web" feature.
+ */
+use std::{collections::HashMap, fmt::Debug};
+pub mod acceptance;
+pub mod canonical;
+pub mod counting;
+pub mod dimacs;
+pub mod encoding;
+pub mod graph;
+pub mod labellings;
+pub mod presets;
+pub mod sat;
+pub mod semantics;
+pub mod verification;
+#[cfg(feature = "web")]
+pub mod worker;
+use petgraph::{
+    algo::{condensation, toposort},
+    graph::DiGraph,
+};
+use semantics::Acceptability::{IN, OUT, UNDEC};
+
+use self::{
+    encoding::Enconding,
+    semantics::{Labelling, Semantics, SemanticsType},
+};
+
+use sat::{
+    Backend, CnfFormula, CnfFormulaExtension, ExtendFormula, Formula, FormulaEncoding, Lit, Models, Var,
+    Vars, SAT,
+};
+
+#[derive(Debug, Clone)]
+pub struct Attack(pub usize, pub usize);
+
+#[derive(Clone)]
+pub struct AF {
+    pub num_of_args: usize,
+    pub attacks: Vec<Attack>,
+    names: Option<HashMap<String, usize>>,
+    sat: SAT,
+    encoding: FormulaEncoding,
+}
+
+impl Debug for AF {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut binding = f.debug_struct("AF");
+        let n: usize = self.num_of_args;
+        let names_by_index: Option<Vec<&str>> = self.names_by_index();
+        binding.field("num_of_args", &n);
+        match names_by_index {
+            Some(names_by_index) => {
+                return binding
+                    .field(
+                        "attacks",
+                        &self
+                            .attacks
+                            .iter()
+                            .map(|att| {
+                                let Attack(origin, target) = att;
+
+                                (
+                                    names_by_index.get(*origin).unwrap_or(&"null"),
+                                    names_by_index.get(*target).unwrap_or(&"null"),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .finish();
+            }
+            None => binding.field("attacks", &self.attacks).finish(),
+        }
+    }
+}
+
+/** The trait can't report solver failures, which only come from external backends:
+ * its methods panic on them, see `AF::all_labellings` for the fallible ones.
+ */
+impl semantics::Semantics for AF {
+    fn complete(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::COMPLETE)
+    }
+    fn admissible(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::ADMISSIBLE)
+    }
+    fn conflict_free(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::CONFLICTFREE)
+    }
+    fn stable(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::STABLE)
+    }
+
+    fn preferred(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::PREFERRED)
+    }
+
+    fn naive(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::NAIVE)
+    }
+
+    fn cf2(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::CF2)
+    }
+
+    fn stage2(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::STAGE2)
+    }
+
+    fn semi_stable(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::SEMISTABLE)
+    }
+
+    fn stage(&self) -> Vec<Labelling> {
+        self.solved(SemanticsType::STAGE)
+    }
+
+    fn ideal(&self) -> Labelling {
+        self.largest_admissible_within(&self.preferred())
+    }
+
+    fn eager(&self) -> Labelling {
+        self.largest_admissible_within(&self.semi_stable())
+    }
+
+    /* Least fixpoint of the characteristic function, computed by propagation:
+    an argument is IN once all its attackers are OUT, and OUT once some attacker is IN */
+    fn grounded(&self) -> Labelling {
+        let n = self.num_of_args;
+        let mut labels = vec![UNDEC; n];
+        let mut attacked_by = vec![vec![]; n];
+        let mut not_out_attackers = vec![0; n];
+        for Attack(origin, target) in &self.attacks {
+            attacked_by[*origin].push(*target);
+            not_out_attackers[*target] += 1;
+        }
+        let mut queue = (0..n)
+            .filter(|&i| not_out_attackers[i] == 0)
+            .collect::<Vec<usize>>();
+        while let Some(arg) = queue.pop() {
+            if labels[arg] != UNDEC {
+                continue;
+            }
+            labels[arg] = IN;
+            for &target in &attacked_by[arg] {
+                if labels[target] != UNDEC {
+                    continue;
+                }
+                labels[target] = OUT;
+                for &defended in &attacked_by[target] {
+                    not_out_attackers[defended] -= 1;
+                    if not_out_attackers[defended] == 0 {
+                        queue.push(defended);
+                    }
+                }
+            }
+        }
+        Labelling(labels)
+    }
+}
+
+impl AF {
+    /** Creates a new Argumentation Framework from an attack relation.
+     * The framework has arguments 0, 1, ..., max; where max is the highest number in `attacks`
+     */
+    pub fn new(attacks: Vec<Attack>) -> AF {
+        let max = attacks
+            .iter()
+            .flat_map(|Attack(origin, target)| vec![origin, target])
+            .max();
+        AF {
+            num_of_args: match max {
+                Some(x) => x + 1,
+                None => 0,
+            },
+            attacks,
+            names: None,
+            sat: SAT::default(),
+            encoding: FormulaEncoding::default(),
+        }
+    }
+
+    pub fn new_named(attacks: Vec<Attack>, names: HashMap<String, usize>) -> AF {
+        AF {
+            num_of_args: names.len(),
+            attacks,
+            names: Some(names),
+            sat: SAT::default(),
+            encoding: FormulaEncoding::default(),
+        }
+    }
+
+    /** The same framework, solved by the `sat` backend */
+    pub fn with_solver(mut self, sat: SAT) -> AF {
+        self.sat = sat;
+        self
+    }
+
+    pub fn solver(&self) -> &SAT {
+        &self.sat
+    }
+
+    /** The same framework, with its semantics encoded as `encoding` */
+    pub fn with_encoding(mut self, encoding: FormulaEncoding) -> AF {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn encoding(&self) -> FormulaEncoding {
+        self.encoding
+    }
+
+    /** Computes a labelling from a boolean assignment of literals.
+     * Either encoding starts with the IN variables followed by the OUT (or defeated) ones
+     */
+    pub fn label(&self, lits: &Vec<Lit>) -> Labelling {
+        let n = self.num_of_args;
+        Labelling(
+            (0..n)
+                .map(|i| {
+                    if lits[i].is_positive() {
+                        return IN;
+                    }
+                    if lits[n + i].is_positive() {
+                        return OUT;
+                    }
+                    return UNDEC;
+                })
+                .collect(),
+        )
+    }
+
+    /** Next labelling whose IN set is maximal, excluding it from `backend` afterwards.
+     * Algorithm 1 from https://arxiv.org/pdf/1310.4986.pdf, one iteration at a time.
+     * `backend` holds the clauses of `formula` and every exclusion so far. The clauses growing a candidate
+     * only hold under a fresh activation literal of `formula`, so one solver keeps what it learns.
+     */
+    fn next_in_maximal(
+        &self,
+        formula: &mut Formula,
+        backend: &mut dyn Backend,
+    ) -> Result<Option<Labelling>, String> {
+        let n = self.num_of_args;
+        if !backend.solve(&[])? {
+            return Ok(None);
+        }
+        let Some(mut pref_candidate) = backend.model() else {
+            return Ok(None);
+        };
+        loop {
+            let active = formula.cnf.new_var().positive();
+            let mut assumptions = vec![active];
+            let mut remaining = vec![!active];
+            for i in 0..n {
+                let lit = formula.vars.i[i].positive();
+                if pref_candidate[lit.index()].is_positive() {
+                    // IN
+                    assumptions.push(lit);
+                } else {
+                    // OUT or UNDEC
+                    remaining.push(lit);
+                }
+            }
+            if remaining.len() == 1 {
+                // all are IN
+                break;
+            }
+            backend.add_clause(&remaining);
+            let larger = backend
+                .solve(&assumptions)?
+                .then(|| backend.model())
+                .flatten();
+            // the clause never holds again
+            backend.add_clause(&[!active]);
+            match larger {
+                Some(model) => pref_candidate = model,
+                None => break,
+            }
+        }
+        let optimize_clause = (0..n)
+            .map(|i| formula.vars.i[i].positive())
+            .filter(|lit| !pref_candidate[lit.index()].is_positive()) // when not IN
+            .collect::<Vec<Lit>>();
+        backend.add_clause(&optimize_clause);
+        Ok(Some(self.label(&pref_candidate)))
+    }
+
+    /** Labellings of the next minimal UNDEC set, i.e. whose range (IN and OUT) is maximal,
+     * excluding that set from `backend` afterwards.
+     * Same scheme as `next_in_maximal`, but shrinking UNDEC instead of growing IN.
+     * Since several labellings may share a minimal UNDEC set, they're all models of the returned solver,
+     * a small one of its own since every UNDEC variable is fixed.
+     */
+    fn next_range_maximal(
+        &self,
+        formula: &mut Formula,
+        backend: &mut dyn Backend,
+    ) -> Result<Option<Models>, String> {
+        let n = self.num_of_args;
+        if !backend.solve(&[])? {
+            return Ok(None);
+        }
+        let Some(mut range_candidate) = backend.model() else {
+            return Ok(None);
+        };
+        loop {
+            let active = formula.cnf.new_var().positive();
+            let mut assumptions = vec![active];
+            let mut remaining = vec![!active];
+            for i in 0..n {
+                let var = formula.vars.u[i];
+                if range_candidate[var.index()].is_positive() {
+                    // UNDEC
+                    remaining.push(var.negative());
+                } else {
+                    // IN or OUT
+                    assumptions.push(var.negative());
+                }
+            }
+            if remaining.len() == 1 {
+                // all are decided
+                break;
+            }
+            backend.add_clause(&remaining);
+            let larger = backend
+                .solve(&assumptions)?
+                .then(|| backend.model())
+                .flatten();
+            backend.add_clause(&[!active]);
+            match larger {
+                Some(model) => range_candidate = model,
+                None => break,
+            }
+        }
+        // every labelling with exactly this UNDEC set, on a solver of its own where the set is fixed by units.
+        // Listing them on `backend` under the UNDEC literals as assumptions was slower, for the 2459 labellings
+        // of the n=55 framework of examples/benchmark.rs: semi-stable 1400-1570 ms instead of 580-640 ms,
+        // stage 1600-1650 ms instead of 980-1050 ms
+        let mut same_range = formula.cnf.clone();
+        for i in 0..n {
+            let var = formula.vars.u[i];
+            same_range.add_clause(&[range_candidate[var.index()]]);
+        }
+        let optimize_clause = (0..n)
+            .map(|i| formula.vars.u[i])
+            .filter(|var| range_candidate[var.index()].is_positive()) // when UNDEC
+            .map(|var| var.negative())
+            .collect::<Vec<Lit>>();
+        backend.add_clause(&optimize_clause);
+        Ok(Some(self.sat.models(&same_range)))
+    }
+
+    /** Every labelling of the semantics computed as a whole rather than searched for */
+    fn listed(&self, semantics: SemanticsType) -> Result<Vec<Labelling>, String> {
+        let all = (0..self.num_of_args).collect::<Vec<_>>();
+        let extensions = match semantics {
+            SemanticsType::GROUNDED => return Ok(vec![self.grounded()]),
+            SemanticsType::IDEAL => {
+                let preferred = self.all_labellings(SemanticsType::PREFERRED)?;
+                return Ok(vec![self.largest_admissible_within(&preferred)]);
+            }
+            SemanticsType::EAGER => {
+                let semi_stable = self.all_labellings(SemanticsType::SEMISTABLE)?;
+                return Ok(vec![self.largest_admissible_within(&semi_stable)]);
+            }
+            SemanticsType::CF2 => {
+                self.scc_recursive(&all, &|af: &AF| af.all_labellings(SemanticsType::NAIVE))?
+            }
+            SemanticsType::STAGE2 => {
+                self.scc_recursive(&all, &|af: &AF| af.all_labellings(SemanticsType::STAGE))?
+            }
+            _ => return self.all_labellings(semantics),
+        };
+        Ok(extensions
+            .iter()
+            .map(|inside| self.label_extension(inside))
+            .collect())
+    }
+
+    /** Every labelling of `semantics`, for the `Semantics` trait */
+    fn solved(&self, semantics: SemanticsType) -> Vec<Labelling> {
+        match self.all_labellings(semantics) {
+            Ok(labellings) => labellings,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /** Labels IN the largest admissible set contained in the skeptically accepted arguments,
+     * i.e. those that are IN in every labelling of `labellings`
+     */
+    fn largest_admissible_within(&self, labellings: &[Labelling]) -> Labelling {
+        let n = self.num_of_args;
+        let mut inside = (0..n)
+            .map(|i| labellings.iter().all(|l| l.0.get(i) == Some(&IN)))
+            .collect::<Vec<bool>>();
+        // the intersection is conflict-free, so it's enough to drop undefended arguments until none is left
+        let attacker_map = self.attacker_map();
+        loop {
+            let mut attacked = vec![false; n];
+            for Attack(origin, target) in &self.attacks {
+                if inside[*origin] {
+                    attacked[*target] = true;
+                }
+            }
+            let mut changed = false;
+            for i in 0..n {
+                if inside[i] && !attacker_map[i].iter().all(|&j| attacked[j]) {
+                    inside[i] = false;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return self.label_extension(&inside);
+            }
+        }
+    }
+
+    /** SCC-recursive schema restricted to `args`, returning the IN set of every extension.
+     * The strongly connected components are visited along the condensation DAG: those arguments of a
+     * component that are attacked by the extension built so far are removed, and the rest is solved
+     * recursively. A single component is solved by `base`.
+     * See https://www.dbai.tuwien.ac.at/staff/gaggl/papers/gaggl-woltran-2013.pdf
+     */
+    fn scc_recursive(
+        &self,
+        args: &[usize],
+        base: &dyn Fn(&AF) -> Result<Vec<Labelling>, String>,
+    ) -> Result<Vec<Vec<bool>>, String> {
+        let n = self.num_of_args;
+        let mut graph = DiGraph::<usize, ()>::new();
+        let mut node_by_arg = vec![None; n];
+        for &arg in args {
+            node_by_arg[arg] = Some(graph.add_node(arg));
+        }
+        for Attack(origin, target) in &self.attacks {
+            if let (Some(origin), Some(target)) = (node_by_arg[*origin], node_by_arg[*target]) {
+                graph.add_edge(origin, target, ());
+            }
+        }
+        let dag = condensation(graph, true);
+        if dag.node_count() <= 1 {
+            let sub = self.restrict(args);
+            return Ok(base(&sub)?
+                .iter()
+                .map(|labelling| {
+                    let mut inside = vec![false; n];
+                    for (i, &arg) in args.iter().enumerate() {
+                        inside[arg] = labelling.0.get(i) == Some(&IN);
+                    }
+                    inside
+                })
+                .collect());
+        }
+        let order = toposort(&dag, None).unwrap_or_default();
+        let mut extensions = vec![vec![false; n]];
+        for component in order {
+            let mut next = vec![];
+            for extension in &extensions {
+                let mut attacked = vec![false; n];
+                for Attack(origin, target) in &self.attacks {
+                    if extension[*origin] {
+                        attacked[*target] = true;
+                    }
+                }
+                let remaining = dag[component]
+                    .iter()
+                    .filter(|&&arg| !attacked[arg])
+                    .copied()
+                    .collect::<Vec<usize>>();
+                for sub_extension in self.scc_recursive(&remaining, base)? {
+                    next.push(
+                        (0..n)
+                            .map(|i| extension[i] || sub_extension[i])
+                            .collect::<Vec<bool>>(),
+                    );
+                }
+            }
+            extensions = next;
+        }
+        Ok(extensions)
+    }
+
+    /** Framework induced by `args`, where `args[k]` becomes argument k */
+    fn restrict(&self, args: &[usize]) -> AF {
+        let mut index = vec![None; self.num_of_args];
+        for (k, &arg) in args.iter().enumerate() {
+            index[arg] = Some(k);
+        }
+        let attacks = self
+            .attacks
+            .iter()
+            .filter_map(
+                |Attack(origin, target)| match (index[*origin], index[*target]) {
+                    (Some(origin), Some(target)) => Some(Attack(origin, target)),
+                    _ => None,
+                },
+            )
+            .collect();
+        AF {
+            num_of_args: args.len(),
+            attacks,
+            names: None,
+            sat: self.sat.clone(),
+            encoding: self.encoding,
+        }
+    }
+
+    /** Labelling of an extension: its arguments are IN, those attacked by it are OUT */
+    fn label_extension(&self, inside: &[bool]) -> Labelling {
+        let mut labels = (0..self.num_of_args)
+            .map(|i| if inside[i] { IN } else { UNDEC })
+            .collect::<Vec<_>>();
+        for Attack(origin, target) in &self.attacks {
+            if inside[*origin] {
+                labels[*target] = OUT;
+            }
+        }
+        Labelling(labels)
+    }
+
+    fn attacker_map(&self) -> Vec<Vec<usize>> {
+        let n = self.num_of_args;
+        let mut result = vec![];
+        for arg in 0..n {
+            let mut attackers = vec![];
+            for Attack(origin, target) in &self.attacks {
+                if *target == arg {
+                    attackers.push(*origin);
+                }
+            }
+            result.push(attackers);
+        }
+        result
+    }
+
+    /** The formula whose models are the labellings of `semantics`, for the semantics that have one */
+    fn labelling_formula(&self, semantics: SemanticsType) -> Option<Formula> {
+        let mut formula = self.create_formula();
+        match semantics {
+            SemanticsType::CONFLICTFREE => self.add_conflict_free_labelling_clauses(&mut formula),
+            SemanticsType::ADMISSIBLE => self.add_admissible_clauses(&mut formula),
+            SemanticsType::COMPLETE => self.add_complete_clauses(&mut formula),
+            SemanticsType::STABLE => self.add_stable_clauses(&mut formula),
+            _ => return None,
+        }
+        Some(formula)
+    }
+
+    fn create_formula(&self) -> Formula {
+        let n = self.num_of_args;
+        let mut cnf = CnfFormula::new();
+        let i = cnf.new_var_iter(n).collect::<Vec<Var>>();
+        let o = cnf.new_var_iter(n).collect::<Vec<Var>>();
+        let u = match self.encoding {
+            FormulaEncoding::LABELLING => cnf.new_var_iter(n).collect::<Vec<Var>>(),
+            FormulaEncoding::EXTENSION => vec![],
+        };
+        Formula {
+            vars: Vars { i, o, u },
+            cnf,
+        }
+    }
+
+    /*
+        Definition 5 https://arxiv.org/pdf/1310.4986.pdf
+        C_in  <-> are the set of clauses (3) and (4)
+        C_out <-> are the set of clauses (5) and (6)
+
+        Each group below adds one family of clauses, so that semantics
+        can be encoded by combining them.
+    */
+
+    /** (1) every argument has exactly one label among IN, OUT and UNDEC.
+     * Without UNDEC variables, no argument is both in the extension and defeated
+     */
+    fn add_exclusivity_clauses(&self, formula: &mut Formula) {
+        let Formula { vars, cnf } = formula;
+        let Vars {
+            i: inn,
+            o: out,
+            u: und,
+        } = vars;
+        for i in 0..self.num_of_args {
+            cnf.add_clause(&[inn[i].negative(), out[i].negative()]);
+            if self.encoding == FormulaEncoding::LABELLING {
+                cnf.add_clause(&[inn[i].positive(), out[i].positive(), und[i].positive()]);
+                cnf.add_clause(&[inn[i].negative(), und[i].negative()]);
+                cnf.add_clause(&[out[i].negative(), und[i].negative()]);
+            }
+        }
+    }
+
+    /** UNDEC variables of the extension encoding, for arguments neither in the extension nor defeated */
+    fn add_undec_vars(&self, formula: &mut Formula) {
+        if !formula.vars.u.is_empty() {
+            return;
+        }
+        let Formula { vars, cnf } = formula;
+        vars.u = cnf.new_var_iter(self.num_of_args).collect::<Vec<Var>>();
+        for i in 0..self.num_of_args {
+            let (inn, out, und) = (vars.i[i], vars.o[i], vars.u[i]);
+            cnf.add_clause(&[inn.positive(), out.positive(), und.positive()]);
+            cnf.add_clause(&[inn.negative(), und.negative()]);
+            cnf.add_clause(&[out.negative(), und.negative()]);
+        }
+    }
+
+    /** No argument is IN together with one of its attackers */
+    fn add_conflict_free_clauses(&self, formula: &mut Formula) {
+        let Formula { vars, cnf } = formula;
+        for Attack(origin, target) in &self.attacks {
+            cnf.add_clause(&[vars.i[*origin].negative(), vars.i[*target].negative()]);
+        }
+    }
+
+    /** (4) an IN argument has all its attackers OUT, i.e. it is defended */
+    fn add_defence_clauses(&self, formula: &mut Formula) {
+        let Formula { vars, cnf } = formula;
+        for Attack(origin, target) in &self.attacks {
+            cnf.add_clause(&[vars.i[*target].negative(), vars.o[*origin].positive()]);
+        }
+    }
+
+    /** (5) and (6) an argument is OUT if and only if some attacker is IN */
+    fn add_out_clauses(&self, formula: &mut Formula) {
+        let Formula { vars, cnf } = formula;
+        let Vars { i: inn, o: out, .. } = vars;
+        let attacker_map = self.attacker_map();
+        for (i, attackers) in attacker_map.iter().enumerate() {
+            // (5)
+            for &j in attackers {
+                cnf.add_clause(&[inn[j].negative(), out[i].positive()]);
+            }
+            // (6)
+            let mut clause6 = attackers
+                .iter()
+                .map(|&j| inn[j].positive())
+                .collect::<Vec<Lit>>();
+            clause6.push(out[i].negative());
+            cnf.add_clause(&clause6);
+        }
+    }
+
+    /** (2) and (3) an argument whose attackers are all OUT is IN */
+    fn add_completeness_clauses(&self, formula: &mut Formula) {
+        let Formula { vars, cnf } = formula;
+        let Vars { i: inn, o: out, .. } = vars;
+        let attacker_map = self.attacker_map();
+        for (i, attackers) in attacker_map.iter().enumerate() {
+            // (2)
+            if attackers.is_empty() {
+                cnf.add_clause(&[inn[i].positive()]);
+                continue;
+            }
+            // (3)
+            let mut clause3 = attackers
+                .iter()
+                .map(|&j| out[j].negative())
+                .collect::<Vec<Lit>>();
+            clause3.push(inn[i].positive());
+            cnf.add_clause(&clause3);
+        }
+    }
+
+    fn add_conflict_free_labelling_clauses(&self, formula: &mut Formula) {
+        self.add_exclusivity_clauses(formula);
+        self.add_conflict_free_clauses(formula);
+        self.add_out_clauses(formula);
+    }
+
+    fn add_admissible_clauses(&self, formula: &mut Formula) {
+        self.add_exclusivity_clauses(formula);
+        self.add_defence_clauses(formula);
+        self.add_out_clauses(formula);
+    }
+
+    fn add_complete_clauses(&self, formula: &mut Formula) {
+        self.add_admissible_clauses(formula);
+        self.add_completeness_clauses(formula);
+    }
+
+    fn add_stable_clauses(&self, formula: &mut Formula) {
+        self.add_complete_clauses(formula);
+        for i in 0..self.num_of_args {
+            match self.encoding {
+                FormulaEncoding::LABELLING => {
+                    let undec_false = formula.vars.u[i].negative();
+                    formula.cnf.add_clause(&vec![undec_false]);
+                }
+                // every argument is in the extension or defeated by it
+                FormulaEncoding::EXTENSION => formula
+                    .cnf
+                    .add_clause(&[formula.vars.i[i].positive(), formula.vars.o[i].positive()]),
+            }
+        }
+    }
+
+    fn add_not_empty_clause(&self, formula: &mut Formula) {
+        formula.cnf.add_clause(
+            &formula
+                .vars
+                .i
+                .iter()
+                .map(|v| v.positive())
+                .collect::<Vec<Lit>>(),
+        )
+    }
+
+    pub fn names_by_index(&self) -> Option<Vec<&str>> {
+        match &self.names {
+            Some(names) => {
+                let mut by_index = vec!["null"; self.num_of_args];
+                for (name, &i) in names {
+                    by_index[i] = &name;
+                }
+                Some(by_index)
+            }
+            None => None,
+        }
+    }
+
+    /** Name of every argument, or its index for unnamed frameworks */
+    pub fn arg_names(&self) -> Vec<String> {
+        match self.names_by_index() {
+            Some(names) => names.iter().map(|name| name.to_string()).collect(),
+            None => (0..self.num_of_args).map(|i| i.to_string()).collect(),
+        }
+    }
+
+    /** Writes the framework as `arg(x).` and `att(x,y).` lines, see `Enconding::SIMPLE` */
+    pub fn to_simple(&self) -> String {
+        let names = self
+            .arg_names()
+            .iter()
+            .map(|name| encoding::statement_name(name))
+            .collect::<Vec<_>>();
+        let mut text = String::new();
+        for name in &names {
+            text.push_str(&format!("arg({}).\n", name));
+        }
+        for Attack(origin, target) in &self.attacks {
+            text.push_str(&format!("att({},{}).\n", names[*origin], names[*target]));
+        }
+        text
+    }
+
+    /** Writes the framework in Trivial Graph Format, see `Enconding::TGF`.
+     * Nodes are numbered from 1 and named after their argument, so that names may hold whitespace
+     */
+    pub fn to_tgf(&self) -> String {
+        let mut text = String::new();
+        for (k, name) in self.arg_names().iter().enumerate() {
+            text.push_str(&format!("{} {}\n", k + 1, name));
+        }
+        text.push_str("#\n");
+        for Attack(origin, target) in &self.attacks {
+            text.push_str(&format!("{} {}\n", origin + 1, target + 1));
+        }
+        text
+    }
+}
+
+impl From<Enconding> for AF {
+    fn from(enc: Enconding) -> Self {
+        match enc {
+            Enconding::SIMPLE(labels, attacks)
+            | Enconding::TGF(labels, attacks)
+            | Enconding::DOT(labels, attacks)
+            | Enconding::AIF(labels, attacks)
+            | Enconding::GRAPHML(labels, attacks) => {
+                let mut att = vec![];
+                let mut index_by_label: HashMap<String, usize> = HashMap::new();
+                for (i, label) in labels.iter().enumerate() {
+                    index_by_label.insert(label.to_owned(), i);
+                }
+                for (origin, target) in attacks {
+                    if let Some(&origin_i) = index_by_label.get(&origin) {
+                        if let Some(&target_i) = index_by_label.get(&target) {
+                            att.push(Attack(origin_i, target_i));
+                        }
+                    }
+                }
+                AF::new_named(att, index_by_label)
+            }
+            Enconding::ICCMA(num_of_args, attacks) => {
+                let names = (0..num_of_args)
+                    .map(|i| ((i + 1).to_string(), i))
+                    .collect::<HashMap<String, usize>>();
+                let att = attacks
+                    .iter()
+                    .map(|&(origin, target)| Attack(origin - 1, target - 1))
+                    .collect();
+                AF::new_named(att, names)
+            }
+            Enconding::ERROR(_) => AF::new(vec![]),
+        }
+    }
+}
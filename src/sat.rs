@@ -0,0 +1,212 @@
+/*! The SAT layer. Formulas are built as `CnfFormula`s and solved by a `Backend`,
+ * chosen per framework with `SAT`; varisat is the one built in, and native builds can run solver binaries.
+ */
+use varisat::solver::Solver;
+pub use varisat::{CnfFormula, ExtendFormula, Lit, Var};
+
+pub mod external;
+
+/** A SAT solver the encodings can be solved with.
+ * Clauses are kept between calls, so that a backend can be asked again after adding more.
+ */
+pub trait Backend {
+    fn add_clause(&mut self, clause: &[Lit]);
+
+    /** Whether the clauses are satisfiable with every literal of `assumptions` true,
+     * or why the solver couldn't tell. Assumptions only hold for this call.
+     */
+    fn solve(&mut self, assumptions: &[Lit]) -> Result<bool, String>;
+
+    /** A value for every variable, from the last call when it was satisfiable and no clause was added since */
+    fn model(&self) -> Option<Vec<Lit>>;
+
+    /** Every clause of `cnf`, whose variables all get a value in the models even when unused */
+    fn add_formula(&mut self, cnf: &CnfFormula) {
+        for clause in cnf.iter() {
+            self.add_clause(clause);
+        }
+    }
+
+    /** Excludes the model of the last satisfiable call from every later one */
+    fn add_blocking_clause(&mut self) {
+        if let Some(model) = self.model() {
+            let exclude = model.iter().map(|&lit| !lit).collect::<Vec<Lit>>();
+            self.add_clause(&exclude);
+        }
+    }
+}
+
+/** The varisat CDCL solver, running in process (and in the browser) */
+#[derive(Default)]
+pub struct Varisat {
+    solver: Solver<'static>,
+}
+
+impl Backend for Varisat {
+    fn add_clause(&mut self, clause: &[Lit]) {
+        self.solver.add_clause(clause);
+    }
+
+    fn solve(&mut self, assumptions: &[Lit]) -> Result<bool, String> {
+        self.solver.assume(assumptions);
+        self.solver.solve().map_err(|error| error.to_string())
+    }
+
+    fn model(&self) -> Option<Vec<Lit>> {
+        self.solver.model()
+    }
+
+    fn add_formula(&mut self, cnf: &CnfFormula) {
+        self.solver.add_formula(cnf);
+    }
+}
+
+/** Which backend solves the formulas of a framework, see `AF::with_solver` */
+#[derive(Debug, Clone, Default)]
+pub enum SAT {
+    #[default]
+    VARISAT,
+    /* a solver binary and its arguments, see `external::External` */
+    EXTERNAL(Vec<String>),
+    /* any other backend, created by the function */
+    CUSTOM(fn() -> Box<dyn Backend>),
+}
+
+impl SAT {
+    /** A new backend of this kind, without clauses */
+    pub fn backend(&self) -> Box<dyn Backend> {
+        match self {
+            SAT::VARISAT => Box::<Varisat>::default(),
+            SAT::EXTERNAL(command) => Box::new(external::External::new(command.clone())),
+            SAT::CUSTOM(create) => create(),
+        }
+    }
+
+    pub fn solve(&self, cnf: &CnfFormula) -> Result<Option<Vec<Lit>>, String> {
+        self.solve_assuming(cnf, &[])
+    }
+
+    /** A model of `cnf` where every literal of `assumptions` is true */
+    pub fn solve_assuming(
+        &self,
+        cnf: &CnfFormula,
+        assumptions: &[Lit],
+    ) -> Result<Option<Vec<Lit>>, String> {
+        let mut backend = self.backend();
+        backend.add_formula(cnf);
+        match backend.solve(assumptions)? {
+            true => Ok(backend.model()),
+            false => Ok(None),
+        }
+    }
+
+    pub fn enumerate(&self, cnf: &CnfFormula) -> Result<Vec<Vec<Lit>>, String> {
+        self.models(cnf).collect()
+    }
+
+    /** Number of models of `cnf`, found one at a time and never kept */
+    pub fn count(&self, cnf: &CnfFormula) -> Result<usize, String> {
+        self.models(cnf)
+            .try_fold(0, |count, model| model.map(|_| count + 1))
+    }
+
+    /** Models of `cnf`, each one found only when asked for */
+    pub fn models(&self, cnf: &CnfFormula) -> Models {
+        let mut backend = self.backend();
+        backend.add_formula(cnf);
+        Models {
+            backend,
+            failed: false,
+        }
+    }
+}
+
+/** `cnf` in the DIMACS CNF format, where variable k is number k + 1 */
+pub fn dimacs(cnf: &CnfFormula) -> String {
+    let mut text = vec![];
+    let _ = varisat::dimacs::write_dimacs(&mut text, cnf);
+    String::from_utf8(text).unwrap_or_default()
+}
+
+/** A single incremental solver, that excludes every model it finds before looking for the next one.
+ * It stops after the first call the solver fails.
+ */
+pub struct Models {
+    backend: Box<dyn Backend>,
+    failed: bool,
+}
+
+impl Iterator for Models {
+    type Item = Result<Vec<Lit>, String>;
+
+    fn next(&mut self) -> Option<Result<Vec<Lit>, String>> {
+        if self.failed {
+            return None;
+        }
+        match self.backend.solve(&[]) {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(error) => {
+                self.failed = true;
+                return Some(Err(error));
+            }
+        }
+        let model = self.backend.model()?;
+        self.backend.add_blocking_clause();
+        Some(Ok(model))
+    }
+}
+
+/** How labellings are encoded as formulas, see `AF::with_encoding` */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "web", derive(serde::Serialize, serde::Deserialize))]
+pub enum FormulaEncoding {
+    /* IN, OUT and UNDEC variables for every argument, Definition 5 of https://arxiv.org/pdf/1310.4986.pdf */
+    #[default]
+    LABELLING,
+    /* a variable for every argument in the extension, and an auxiliary one for every argument it defeats.
+    UNDEC variables are only added for the semi-stable and stage semantics, defined from the others */
+    EXTENSION,
+}
+
+const FORMULA_ENCODING_NAME: [&str; 2] = ["Labelling", "Extension"];
+
+impl From<FormulaEncoding> for String {
+    fn from(encoding: FormulaEncoding) -> String {
+        String::from(FORMULA_ENCODING_NAME[encoding as usize])
+    }
+}
+
+impl From<String> for FormulaEncoding {
+    fn from(name: String) -> FormulaEncoding {
+        match name.as_str() {
+            "Extension" => FormulaEncoding::EXTENSION,
+            _ => FormulaEncoding::LABELLING,
+        }
+    }
+}
+
+/* OUT variables are the "defeated" ones of the extension encoding, and UNDEC ones may be missing */
+pub struct Vars {
+    pub i: Vec<Var>,
+    pub o: Vec<Var>,
+    pub u: Vec<Var>,
+}
+pub struct Formula {
+    pub vars: Vars,
+    pub cnf: CnfFormula,
+}
+
+pub trait CnfFormulaExtension {
+  fn clone(&self) -> CnfFormula;
+}
+
+impl CnfFormulaExtension for CnfFormula {
+  fn clone(&self) -> CnfFormula {
+      let mut cnf = CnfFormula::new();
+      for clause in self.iter() {
+        cnf.add_clause(clause);
+      }
+      cnf
+  }
+}
\ No newline at end of file
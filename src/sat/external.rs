@@ -0,0 +1,122 @@
+/*! Any SAT solver binary speaking DIMACS, such as kissat, cadical or minisat, run once per call.
+ * Only for native builds, browsers can't run processes.
+ */
+use std::{
+    env, fs,
+    process::{self, Command},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::{dimacs, Backend, CnfFormula, CnfFormulaExtension, ExtendFormula, Lit};
+
+/* temporary files of the process get different names */
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/** A solver binary run as `command... file.cnf`, reading the `s SATISFIABLE` and `v ...` lines it prints.
+ * Every call writes every clause again, with the assumptions as unit clauses, so enumeration re-invokes it
+ * after each blocking clause. A call fails when the binary can't be run or gives no answer.
+ */
+pub struct External {
+    command: Vec<String>,
+    cnf: CnfFormula,
+    model: Option<Vec<Lit>>,
+}
+
+impl External {
+    pub fn new(command: Vec<String>) -> External {
+        External {
+            command,
+            cnf: CnfFormula::new(),
+            model: None,
+        }
+    }
+
+    /** Runs the solver on `cnf`, giving `None` when it's unsatisfiable */
+    fn run(&self, cnf: &CnfFormula) -> Result<Option<Vec<Lit>>, String> {
+        let Some((program, args)) = self.command.split_first() else {
+            return Err(String::from("no SAT solver command"));
+        };
+        let call = CALLS.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("af-{}-{}.cnf", process::id(), call));
+        if let Err(error) = fs::write(&path, dimacs(cnf)) {
+            return Err(format!("cannot write {}, {}", path.display(), error));
+        }
+        let output = Command::new(program).args(args).arg(&path).output();
+        let _ = fs::remove_file(&path);
+        let output = match output {
+            Ok(output) => output,
+            Err(error) => return Err(format!("cannot run {}, {}", program, error)),
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut satisfiable = None;
+        let mut values = vec![];
+        for line in stdout.lines() {
+            match line.split_whitespace().next() {
+                Some("s") => {
+                    satisfiable = match line.split_whitespace().nth(1) {
+                        Some("SATISFIABLE") => Some(true),
+                        Some("UNSATISFIABLE") => Some(false),
+                        _ => None,
+                    }
+                }
+                Some("v") => values.extend(
+                    line.split_whitespace()
+                        .skip(1)
+                        .filter_map(|number| number.parse::<isize>().ok())
+                        .filter(|&number| number != 0),
+                ),
+                _ => {}
+            }
+        }
+        match satisfiable {
+            Some(true) => {
+                // variables left out by the solver can take any value
+                let mut model = (0..cnf.var_count())
+                    .map(|index| Lit::from_index(index, false))
+                    .collect::<Vec<Lit>>();
+                for number in values {
+                    let lit = Lit::from_dimacs(number);
+                    if lit.index() < model.len() {
+                        model[lit.index()] = lit;
+                    }
+                }
+                Ok(Some(model))
+            }
+            Some(false) => Ok(None),
+            None => Err(format!("{} gave no answer:\n{}", program, stdout)),
+        }
+    }
+}
+
+impl Backend for External {
+    fn add_clause(&mut self, clause: &[Lit]) {
+        self.cnf.add_clause(clause);
+    }
+
+    fn solve(&mut self, assumptions: &[Lit]) -> Result<bool, String> {
+        self.model = match assumptions.is_empty() {
+            true => self.run(&self.cnf)?,
+            false => {
+                let mut cnf = CnfFormulaExtension::clone(&self.cnf);
+                cnf.set_var_count(self.cnf.var_count());
+                for &lit in assumptions {
+                    cnf.add_clause(&[lit]);
+                }
+                self.run(&cnf)?
+            }
+        };
+        Ok(self.model.is_some())
+    }
+
+    fn model(&self) -> Option<Vec<Lit>> {
+        self.model.clone()
+    }
+
+    fn add_formula(&mut self, cnf: &CnfFormula) {
+        self.cnf
+            .set_var_count(self.cnf.var_count().max(cnf.var_count()));
+        for clause in cnf.iter() {
+            self.cnf.add_clause(clause);
+        }
+    }
+}
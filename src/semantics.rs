@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "web", derive(serde::Serialize, serde::Deserialize))]
+pub enum Acceptability {
+    IN,
+    OUT,
+    UNDEC,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "web", derive(serde::Serialize, serde::Deserialize))]
+pub struct Labelling(pub Vec<Acceptability>);
+
+pub trait Semantics {
+    /** One labelling per conflict-free set, where OUT are exactly the arguments it attacks */
+    fn conflict_free(&self) -> Vec<Labelling>;
+    /** One labelling per admissible set, where OUT are exactly the arguments it attacks */
+    fn admissible(&self) -> Vec<Labelling>;
+    fn complete(&self) -> Vec<Labelling>;
+    fn stable(&self) -> Vec<Labelling>;
+    fn preferred(&self) -> Vec<Labelling>;
+    fn semi_stable(&self) -> Vec<Labelling>;
+    fn stage(&self) -> Vec<Labelling>;
+    fn naive(&self) -> Vec<Labelling>;
+    fn cf2(&self) -> Vec<Labelling>;
+    fn stage2(&self) -> Vec<Labelling>;
+    /** The grounded labelling always exists and is unique */
+    fn grounded(&self) -> Labelling;
+    /** The ideal labelling is unique, and is built from the preferred labellings */
+    fn ideal(&self) -> Labelling;
+    /** The eager labelling is unique, and is built from the semi-stable labellings */
+    fn eager(&self) -> Labelling;
+    fn get_semantics(&self, criteria: SemanticsType) -> Vec<Labelling> {
+        match criteria {
+            SemanticsType::COMPLETE => self.complete(),
+            SemanticsType::GROUNDED => vec![self.grounded()],
+            SemanticsType::PREFERRED => self.preferred(),
+            SemanticsType::STABLE => self.stable(),
+            SemanticsType::ADMISSIBLE => self.admissible(),
+            SemanticsType::CONFLICTFREE => self.conflict_free(),
+            SemanticsType::SEMISTABLE => self.semi_stable(),
+            SemanticsType::STAGE => self.stage(),
+            SemanticsType::IDEAL => vec![self.ideal()],
+            SemanticsType::EAGER => vec![self.eager()],
+            SemanticsType::NAIVE => self.naive(),
+            SemanticsType::CF2 => self.cf2(),
+            SemanticsType::STAGE2 => self.stage2(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum SemanticsType {
+    COMPLETE,
+    GROUNDED,
+    PREFERRED,
+    STABLE,
+    ADMISSIBLE,
+    CONFLICTFREE,
+    SEMISTABLE,
+    STAGE,
+    IDEAL,
+    EAGER,
+    NAIVE,
+    CF2,
+    STAGE2,
+}
+pub const SEMANTICS_TYPES: &'static [SemanticsType] = &[
+    SemanticsType::COMPLETE,
+    SemanticsType::GROUNDED,
+    SemanticsType::PREFERRED,
+    SemanticsType::STABLE,
+    SemanticsType::ADMISSIBLE,
+    SemanticsType::CONFLICTFREE,
+    SemanticsType::SEMISTABLE,
+    SemanticsType::STAGE,
+    SemanticsType::IDEAL,
+    SemanticsType::EAGER,
+    SemanticsType::NAIVE,
+    SemanticsType::CF2,
+    SemanticsType::STAGE2,
+];
+const SEMANTICS_NAME: &'static [&'static str] = &[
+    "Complete",
+    "Grounded",
+    "Preferred",
+    "Stable",
+    "Admissible",
+    "Conflict-free",
+    "Semi-stable",
+    "Stage",
+    "Ideal",
+    "Eager",
+    "Naive",
+    "CF2",
+    "Stage2",
+];
+
+/* ICCMA abbreviation of every semantics, in the order of SEMANTICS_TYPES */
+pub const SEMANTICS_ABBREVIATION: &[&str] = &[
+    "CO", "GR", "PR", "ST", "ADM", "CF", "SST", "STG", "ID", "EG", "NA", "CF2", "STG2",
+];
+
+impl SemanticsType {
+    /** The semantics of an ICCMA abbreviation, such as PR for preferred */
+    pub fn from_abbreviation(abbreviation: &str) -> Option<SemanticsType> {
+        SEMANTICS_ABBREVIATION
+            .iter()
+            .position(|s| *s == abbreviation)
+            .map(|i| SEMANTICS_TYPES[i])
+    }
+}
+
+impl Into<String> for SemanticsType {
+    fn into(self) -> String {
+        String::from(SEMANTICS_NAME[self as usize])
+    }
+}
+impl From<String> for SemanticsType {
+    fn from(value: String) -> Self {
+        for i in 0..SEMANTICS_NAME.len() {
+            if value == SEMANTICS_NAME[i] {
+                return SEMANTICS_TYPES[i];
+            }
+        }
+        SemanticsType::STABLE
+    }
+}
+impl Into<usize> for SemanticsType {
+    fn into(self) -> usize {
+        let s: String = self.into();
+        for i in 0..SEMANTICS_NAME.len() {
+            if s == SEMANTICS_NAME[i] {
+                return i;
+            }
+        }
+        panic!("Unknown semantics");
+    }
+}
@@ -0,0 +1,378 @@
+use std::fmt::Display;
+
+
+use crate::{
+    semantics::{
+        Acceptability::{IN, OUT, UNDEC},
+        Labelling, Semantics, SemanticsType,
+    },
+    Attack, AF,
+};
+use crate::sat::{ExtendFormula, Formula, Lit};
+
+/** Why a labelling is not a σ-labelling, or why the solver couldn't check it.
+ * `arg` is the argument whose constraint is violated, if there's one
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub arg: Option<usize>,
+    pub reason: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl AF {
+    /** Checks whether `labelling` is a labelling of `semantics`, explaining the first violation found otherwise */
+    pub fn verify(&self, labelling: &Labelling, semantics: SemanticsType) -> Result<(), Violation> {
+        self.check_size(labelling)?;
+        match semantics {
+            // listed as one labelling per extension, so only that labelling is accepted
+            SemanticsType::CONFLICTFREE => self.check_extension(labelling),
+            SemanticsType::ADMISSIBLE => {
+                self.check_extension(labelling)?;
+                self.check_in_legal(labelling)
+            }
+            SemanticsType::COMPLETE => self.check_complete(labelling),
+            SemanticsType::STABLE => {
+                self.check_complete(labelling)?;
+                match labelling.0.iter().position(|acc| *acc == UNDEC) {
+                    Some(i) => Err(self.violation(i, format!("{} is UNDEC", self.arg_name(i)))),
+                    None => Ok(()),
+                }
+            }
+            SemanticsType::PREFERRED => {
+                self.check_complete(labelling)?;
+                let mut formula = self.create_formula();
+                self.add_complete_clauses(&mut formula);
+                self.check_in_maximal(labelling, formula)
+            }
+            SemanticsType::SEMISTABLE => {
+                self.check_complete(labelling)?;
+                let mut formula = self.create_formula();
+                self.add_complete_clauses(&mut formula);
+                self.check_range_maximal(labelling, formula)
+            }
+            SemanticsType::NAIVE => {
+                self.check_extension(labelling)?;
+                let mut formula = self.create_formula();
+                self.add_conflict_free_labelling_clauses(&mut formula);
+                self.check_in_maximal(labelling, formula)
+            }
+            SemanticsType::STAGE => {
+                self.check_extension(labelling)?;
+                let mut formula = self.create_formula();
+                self.add_conflict_free_labelling_clauses(&mut formula);
+                self.check_range_maximal(labelling, formula)
+            }
+            SemanticsType::GROUNDED => self.check_equal(labelling, &self.grounded(), semantics),
+            SemanticsType::IDEAL | SemanticsType::EAGER => {
+                let expected = self
+                    .listed(semantics)
+                    .map_err(|error| self.unchecked(error))?;
+                self.check_equal(labelling, &expected[0], semantics)
+            }
+            SemanticsType::CF2 | SemanticsType::STAGE2 => {
+                self.check_extension(labelling)?;
+                let is_in = |l: &Labelling| l.0.iter().map(|acc| *acc == IN).collect::<Vec<_>>();
+                if self
+                    .listed(semantics)
+                    .map_err(|error| self.unchecked(error))?
+                    .iter()
+                    .any(|other| is_in(other) == is_in(labelling))
+                {
+                    return Ok(());
+                }
+                let name: String = semantics.into();
+                Err(Violation {
+                    arg: None,
+                    reason: format!("the IN arguments are not a {} extension", name),
+                })
+            }
+        }
+    }
+
+    /** Checks whether the set of arguments `extension` is an extension of `semantics` */
+    pub fn verify_extension(
+        &self,
+        extension: &[usize],
+        semantics: SemanticsType,
+    ) -> Result<(), Violation> {
+        let mut inside = vec![false; self.num_of_args];
+        for &arg in extension {
+            if arg >= self.num_of_args {
+                return Err(Violation {
+                    arg: None,
+                    reason: format!("{} is not an argument", arg),
+                });
+            }
+            inside[arg] = true;
+        }
+        for Attack(origin, target) in &self.attacks {
+            if inside[*origin] && inside[*target] {
+                return Err(self.violation(
+                    *target,
+                    format!(
+                        "{} and {} are both in the set but {} attacks {}",
+                        self.arg_name(*origin),
+                        self.arg_name(*target),
+                        self.arg_name(*origin),
+                        self.arg_name(*target)
+                    ),
+                ));
+            }
+        }
+        self.verify(&self.label_extension(&inside), semantics)
+    }
+
+    fn arg_name(&self, i: usize) -> String {
+        match self.names_by_index() {
+            Some(names) => names[i].to_owned(),
+            None => i.to_string(),
+        }
+    }
+
+    fn violation(&self, arg: usize, reason: String) -> Violation {
+        Violation {
+            arg: Some(arg),
+            reason,
+        }
+    }
+
+    fn unchecked(&self, error: String) -> Violation {
+        Violation {
+            arg: None,
+            reason: format!("cannot be checked, {}", error),
+        }
+    }
+
+    fn check_size(&self, labelling: &Labelling) -> Result<(), Violation> {
+        if labelling.0.len() != self.num_of_args {
+            return Err(Violation {
+                arg: None,
+                reason: format!(
+                    "the labelling has {} labels but there are {} arguments",
+                    labelling.0.len(),
+                    self.num_of_args
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /** No IN argument is attacked by an IN argument */
+    fn check_conflict_free(&self, labelling: &Labelling) -> Result<(), Violation> {
+        let l = &labelling.0;
+        for Attack(origin, target) in &self.attacks {
+            if l[*target] == IN && l[*origin] == IN {
+                return Err(self.violation(
+                    *target,
+                    format!(
+                        "{} is IN but attacker {} is IN",
+                        self.arg_name(*target),
+                        self.arg_name(*origin)
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /** Every attacker of an IN argument is OUT */
+    fn check_in_legal(&self, labelling: &Labelling) -> Result<(), Violation> {
+        let l = &labelling.0;
+        for Attack(origin, target) in &self.attacks {
+            if l[*target] == IN && l[*origin] != OUT {
+                return Err(self.violation(
+                    *target,
+                    format!(
+                        "{} is IN but attacker {} is not OUT",
+                        self.arg_name(*target),
+                        self.arg_name(*origin)
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /** Every OUT argument has an IN attacker */
+    fn check_out_legal(&self, labelling: &Labelling) -> Result<(), Violation> {
+        let l = &labelling.0;
+        for (i, attackers) in self.attacker_map().iter().enumerate() {
+            if l[i] == OUT && !attackers.iter().any(|&j| l[j] == IN) {
+                return Err(self.violation(
+                    i,
+                    format!("{} is OUT but no attacker is IN", self.arg_name(i)),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /** Every UNDEC argument has an attacker that is not OUT, and no IN attacker */
+    fn check_undec_legal(&self, labelling: &Labelling) -> Result<(), Violation> {
+        let l = &labelling.0;
+        for (i, attackers) in self.attacker_map().iter().enumerate() {
+            if l[i] != UNDEC {
+                continue;
+            }
+            if let Some(&j) = attackers.iter().find(|&&j| l[j] == IN) {
+                return Err(self.violation(
+                    i,
+                    format!(
+                        "{} is UNDEC but attacker {} is IN",
+                        self.arg_name(i),
+                        self.arg_name(j)
+                    ),
+                ));
+            }
+            if attackers.iter().all(|&j| l[j] == OUT) {
+                return Err(self.violation(
+                    i,
+                    format!(
+                        "{} is UNDEC but all its attackers are OUT",
+                        self.arg_name(i)
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_admissible(&self, labelling: &Labelling) -> Result<(), Violation> {
+        self.check_in_legal(labelling)?;
+        self.check_out_legal(labelling)
+    }
+
+    fn check_complete(&self, labelling: &Labelling) -> Result<(), Violation> {
+        self.check_admissible(labelling)?;
+        self.check_undec_legal(labelling)
+    }
+
+    /** Conflict-free, and OUT are exactly the arguments attacked by IN ones */
+    fn check_extension(&self, labelling: &Labelling) -> Result<(), Violation> {
+        self.check_conflict_free(labelling)?;
+        self.check_out_legal(labelling)?;
+        let l = &labelling.0;
+        for Attack(origin, target) in &self.attacks {
+            if l[*origin] == IN && l[*target] != OUT {
+                return Err(self.violation(
+                    *target,
+                    format!(
+                        "{} is {:?} but attacker {} is IN",
+                        self.arg_name(*target),
+                        l[*target],
+                        self.arg_name(*origin)
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /** Looks for a labelling of `formula` with the IN arguments of `labelling` and some more */
+    fn check_in_maximal(
+        &self,
+        labelling: &Labelling,
+        mut formula: Formula,
+    ) -> Result<(), Violation> {
+        let mut larger: Vec<Lit> = vec![];
+        for (i, acc) in labelling.0.iter().enumerate() {
+            let lit = formula.vars.i[i].positive();
+            if *acc == IN {
+                formula.cnf.add_clause(&[lit]);
+            } else {
+                larger.push(lit);
+            }
+        }
+        formula.cnf.add_clause(&larger);
+        let model = self
+            .sat
+            .solve(&formula.cnf)
+            .map_err(|error| self.unchecked(error))?;
+        match model {
+            Some(model) => {
+                let witness = self.label(&model);
+                let i = (0..self.num_of_args)
+                    .find(|&i| witness.0[i] == IN && labelling.0[i] != IN)
+                    .unwrap_or_default();
+                Err(self.violation(
+                    i,
+                    format!(
+                        "{} is {:?} but it can be made IN while keeping every IN argument",
+                        self.arg_name(i),
+                        labelling.0[i]
+                    ),
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /** Looks for a labelling of `formula` with fewer UNDEC arguments than `labelling` */
+    fn check_range_maximal(
+        &self,
+        labelling: &Labelling,
+        mut formula: Formula,
+    ) -> Result<(), Violation> {
+        self.add_undec_vars(&mut formula);
+        let mut smaller: Vec<Lit> = vec![];
+        for (i, acc) in labelling.0.iter().enumerate() {
+            let lit = formula.vars.u[i].negative();
+            if *acc == UNDEC {
+                smaller.push(lit);
+            } else {
+                formula.cnf.add_clause(&[lit]);
+            }
+        }
+        formula.cnf.add_clause(&smaller);
+        let model = self
+            .sat
+            .solve(&formula.cnf)
+            .map_err(|error| self.unchecked(error))?;
+        match model {
+            Some(model) => {
+                let witness = self.label(&model);
+                let i = (0..self.num_of_args)
+                    .find(|&i| witness.0[i] != UNDEC && labelling.0[i] == UNDEC)
+                    .unwrap_or_default();
+                Err(self.violation(
+                    i,
+                    format!(
+                        "{} is UNDEC but it can be made {:?} without making any other argument UNDEC",
+                        self.arg_name(i),
+                        witness.0[i]
+                    ),
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn check_equal(
+        &self,
+        labelling: &Labelling,
+        expected: &Labelling,
+        semantics: SemanticsType,
+    ) -> Result<(), Violation> {
+        let name: String = semantics.into();
+        let differs = |i: &usize| labelling.0[*i] != expected.0[*i];
+        match (0..self.num_of_args).find(differs) {
+            Some(i) => Err(self.violation(
+                i,
+                format!(
+                    "{} is {:?} but it is {:?} in the {} labelling",
+                    self.arg_name(i),
+                    labelling.0[i],
+                    expected.0[i],
+                    name.to_lowercase()
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+/*! Messages between the front-end and the worker solving in the background, sent as JSON.
+ * Every request belongs to a job, started by `SOLVE`, and responses of older jobs are ignored.
+ */
+use serde::{Deserialize, Serialize};
+
+use crate::{acceptance::Acceptance, sat::FormulaEncoding, semantics::Labelling};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request {
+    /* parses `text` and lists labellings of the semantics of index `semantics` until there are `wanted`,
+    counting the `known` ones the page already has. Those are in the order of the arguments of `text`,
+    and are excluded from the search instead of being sent again */
+    SOLVE {
+        job: u32,
+        text: String,
+        semantics: usize,
+        encoding: FormulaEncoding,
+        known: Vec<Labelling>,
+        wanted: usize,
+    },
+    /* lists labellings of the current job until there are `wanted` */
+    MORE {
+        job: u32,
+        wanted: usize,
+    },
+    /* counts the labellings of the current job */
+    COUNT {
+        job: u32,
+    },
+    /* credulous and skeptical acceptance of `arg` under each semantics in `semantics` */
+    ACCEPTANCE {
+        job: u32,
+        arg: usize,
+        semantics: Vec<usize>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    FOUND {
+        job: u32,
+        labelling: Labelling,
+    },
+    /* every labelling wanted so far was found */
+    LISTED {
+        job: u32,
+    },
+    /* there are no more labellings */
+    EXHAUSTED {
+        job: u32,
+    },
+    COUNTED {
+        job: u32,
+        count: usize,
+    },
+    /* the request belongs to an older job, and was dropped */
+    STALE {
+        job: u32,
+    },
+    /* credulous and skeptical acceptance, in the order of the request */
+    ACCEPTED {
+        job: u32,
+        arg: usize,
+        results: Vec<(Acceptance, Acceptance)>,
+    },
+    /* the solver failed, so the request has no answer */
+    FAILED {
+        job: u32,
+        error: String,
+    },
+}
+
+impl Request {
+    pub fn job(&self) -> u32 {
+        match self {
+            Request::SOLVE { job, .. }
+            | Request::MORE { job, .. }
+            | Request::COUNT { job }
+            | Request::ACCEPTANCE { job, .. } => *job,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Option<Request> {
+        serde_json::from_str(json).ok()
+    }
+}
+
+impl Response {
+    pub fn job(&self) -> u32 {
+        match self {
+            Response::FOUND { job, .. }
+            | Response::LISTED { job }
+            | Response::EXHAUSTED { job }
+            | Response::COUNTED { job, .. }
+            | Response::STALE { job }
+            | Response::ACCEPTED { job, .. }
+            | Response::FAILED { job, .. } => *job,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Option<Response> {
+        serde_json::from_str(json).ok()
+    }
+}
@@ -0,0 +1,11 @@
+mod common;
+
+use common::{af, CHAIN, TWO_CYCLES};
+
+#[test]
+fn canonical_hash_ignores_how_the_framework_is_written() {
+    let reordered = af("c\nb\na\n#\nb c\na b\na b\n");
+    assert_eq!(reordered.canonical_text(), af(CHAIN).canonical_text());
+    assert_eq!(reordered.canonical_hash(), af(CHAIN).canonical_hash());
+    assert_ne!(af(CHAIN).canonical_hash(), af(TWO_CYCLES).canonical_hash());
+}
@@ -0,0 +1,186 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{self, Command},
+};
+
+use af::{
+    encoding::Enconding,
+    semantics::{
+        Acceptability::IN, Semantics, SemanticsType, SEMANTICS_ABBREVIATION, SEMANTICS_TYPES,
+    },
+    AF,
+};
+
+/* a <-> b -> c -> d, with a self-attacking e */
+const TEXT: &str =
+    "arg(a).\narg(b).\narg(c).\narg(d).\narg(e).\natt(a,b).\natt(b,a).\natt(b,c).\natt(c,d).\natt(e,e).";
+
+/** `text` in a file of its own, named after `name` */
+fn framework_file(name: &str, text: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("af-cli-{}-{}", process::id(), name));
+    fs::write(&path, text).unwrap();
+    path
+}
+
+/** Standard output and exit code of af-cli */
+fn cli(args: &[&str]) -> (String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_af-cli"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        output.status.code().unwrap(),
+    )
+}
+
+/** Every problem listed by `af-cli --problems` */
+fn problems() -> Vec<String> {
+    let (problems, code) = cli(&["--problems"]);
+    assert_eq!(code, 0);
+    problems
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(String::from)
+        .collect()
+}
+
+/** Sorted extensions of a `[[a,b],[c]]` answer */
+fn extensions(answer: &str) -> Vec<String> {
+    let inner = &answer.trim()[1..answer.trim().len() - 1];
+    let mut extensions = vec![];
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '[' => start = i,
+            ']' => extensions.push(String::from(&inner[start..=i])),
+            _ => {}
+        }
+    }
+    extensions.sort();
+    extensions
+}
+
+#[test]
+fn every_task_and_semantics() {
+    let af = AF::from(Enconding::parse(String::from(TEXT)));
+    let names = af.arg_names();
+    let path = framework_file("tasks.apx", TEXT);
+    let file = path.to_str().unwrap();
+    // every semantics af-cli knows of, by the abbreviation of its EE problem
+    let abbreviations = problems()
+        .iter()
+        .filter_map(|problem| problem.strip_prefix("EE-").map(String::from))
+        .collect::<Vec<_>>();
+    assert_eq!(abbreviations.len(), SEMANTICS_TYPES.len());
+    for abbreviation in abbreviations {
+        let semantics = SemanticsType::from_abbreviation(&abbreviation).unwrap();
+        let problem = |task: &str| format!("{}-{}", task, abbreviation);
+        let mut expected = af
+            .get_semantics(semantics)
+            .iter()
+            .map(|labelling| {
+                let args = (0..names.len())
+                    .filter(|&i| labelling.0[i] == IN)
+                    .map(|i| names[i].as_str())
+                    .collect::<Vec<_>>();
+                format!("[{}]", args.join(","))
+            })
+            .collect::<Vec<_>>();
+        expected.sort();
+
+        let (ee, code) = cli(&["-p", &problem("EE"), "-f", file, "-fo", "apx"]);
+        assert_eq!(code, 0);
+        assert_eq!(extensions(&ee), expected, "{}", problem("EE"));
+        let (se, _) = cli(&["-p", &problem("SE"), "-f", file]);
+        match expected.is_empty() {
+            true => assert_eq!(se.trim(), "NO"),
+            false => assert!(
+                expected.contains(&String::from(se.trim())),
+                "{}",
+                problem("SE")
+            ),
+        }
+        let (ce, _) = cli(&["-p", &problem("CE"), "-f", file]);
+        assert_eq!(ce.trim(), expected.len().to_string(), "{}", problem("CE"));
+        for (arg, name) in names.iter().enumerate() {
+            let (dc, _) = cli(&["-p", &problem("DC"), "-f", file, "-a", name]);
+            let (ds, _) = cli(&["-p", &problem("DS"), "-f", file, "-a", name]);
+            let yes = |accepted: bool| if accepted { "YES" } else { "NO" };
+            assert_eq!(
+                dc.trim(),
+                yes(af.credulous(arg, semantics).unwrap().accepted)
+            );
+            assert_eq!(
+                ds.trim(),
+                yes(af.skeptical(arg, semantics).unwrap().accepted)
+            );
+        }
+    }
+    // the same answers in the other encoding
+    let (ee, _) = cli(&["-p", "EE-PR", "-f", file, "-enc", "extension"]);
+    assert_eq!(extensions(&ee), vec!["[a,c]", "[b,d]"]);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn problems_and_formats() {
+    let problems = problems();
+    // every task for every semantics
+    for task in ["SE", "EE", "DC", "DS", "CE"] {
+        for abbreviation in SEMANTICS_ABBREVIATION {
+            assert!(problems.contains(&format!("{}-{}", task, abbreviation)));
+        }
+    }
+    assert_eq!(problems.len(), 5 * SEMANTICS_TYPES.len());
+    assert_eq!(
+        cli(&["--formats"]),
+        (String::from("[apx,tgf,i23,dot,aif,graphml]\n"), 0)
+    );
+    let path = framework_file("iccma.i23", "p af 2\n1 2\n");
+    let (answer, _) = cli(&["-p", "EE-GR", "-f", path.to_str().unwrap(), "-fo", "i23"]);
+    assert_eq!(answer.trim(), "[[1]]");
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn errors_exit_with_1() {
+    let path = framework_file("errors.apx", TEXT);
+    let file = path.to_str().unwrap();
+    let invalid = framework_file("invalid.apx", "arg(a).\natt(a,b).\n");
+    for args in [
+        vec!["-f", file],
+        vec!["-p", "EE-XY", "-f", file],
+        vec!["-p", "XX-CO", "-f", file],
+        vec!["-p", "EE-CO"],
+        vec!["-p", "EE-CO", "-f", "/nonexistent/framework.apx"],
+        vec!["-p", "EE-CO", "-f", file, "-fo", "xml"],
+        vec!["-p", "EE-CO", "-f", file, "-enc", "bits"],
+        vec!["-p", "DC-CO", "-f", file],
+        vec!["-p", "DC-CO", "-f", file, "-a", "z"],
+        vec!["-p", "EE-CO", "-f", invalid.to_str().unwrap()],
+        vec!["-x", "y"],
+        vec!["-p"],
+    ] {
+        assert_eq!(cli(&args).1, 1, "{:?}", args);
+    }
+    // a solver that can't be run, whatever the task, before anything is printed
+    for problem in ["SE-CO", "EE-PR", "DC-ST", "DS-SST", "CE-CF2"] {
+        let args = [
+            "-p",
+            problem,
+            "-f",
+            file,
+            "-a",
+            "a",
+            "-sat",
+            "/nonexistent/solver",
+        ];
+        assert_eq!(cli(&args), (String::new(), 1), "{}", problem);
+    }
+    fs::remove_file(path).unwrap();
+    fs::remove_file(invalid).unwrap();
+}
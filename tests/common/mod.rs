@@ -0,0 +1,14 @@
+/*! Frameworks and helpers shared by the integration tests, each of which uses only some of them */
+#![allow(dead_code)]
+
+use af::{encoding::Enconding, AF};
+
+/* a -> b -> c */
+pub const CHAIN: &str = "arg(a).\narg(b).\narg(c).\natt(a,b).\natt(b,c).\n";
+/* a <-> b, c <-> d */
+pub const TWO_CYCLES: &str =
+    "arg(a).\narg(b).\narg(c).\narg(d).\natt(a,b).\natt(b,a).\natt(c,d).\natt(d,c).";
+
+pub fn af(text: &str) -> AF {
+    AF::from(Enconding::parse(String::from(text)))
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use af::{
+    encoding::Enconding,
+    sat::{FormulaEncoding, SAT},
+    semantics::SemanticsType,
+    AF,
+};
+use common::{af, CHAIN};
+use varisat::dimacs::DimacsParser;
+
+#[test]
+fn variables_are_named_in_the_header() {
+    let af = af(CHAIN);
+    let text = af.to_dimacs(SemanticsType::COMPLETE).unwrap();
+    let lines = text.lines().collect::<Vec<_>>();
+    assert!(lines.contains(&"c 1 in_a"));
+    assert!(lines.contains(&"c 5 out_b"));
+    assert!(lines.contains(&"c 9 undec_c"));
+    assert!(lines.iter().any(|line| line.starts_with("p cnf 9 ")));
+    assert!(af.to_dimacs(SemanticsType::PREFERRED).is_none());
+
+    let text = af
+        .with_encoding(FormulaEncoding::EXTENSION)
+        .to_dimacs(SemanticsType::COMPLETE)
+        .unwrap();
+    let lines = text.lines().collect::<Vec<_>>();
+    assert!(lines.contains(&"c 5 defeated_b"));
+    assert!(lines.iter().any(|line| line.starts_with("p cnf 6 ")));
+}
+
+#[test]
+fn models_are_the_labellings() {
+    let text = "arg(a).\narg(b).\narg(c).\natt(a,b).\natt(b,a).\natt(b,c).\natt(c,c).";
+    let labelling = AF::from(Enconding::parse(String::from(text)));
+    let extension = labelling.clone().with_encoding(FormulaEncoding::EXTENSION);
+    for af in [labelling, extension] {
+        for semantics in [
+            SemanticsType::CONFLICTFREE,
+            SemanticsType::ADMISSIBLE,
+            SemanticsType::COMPLETE,
+            SemanticsType::STABLE,
+        ] {
+            let dimacs = af.to_dimacs(semantics).unwrap();
+            let cnf = DimacsParser::parse(dimacs.as_bytes()).unwrap();
+            assert_eq!(SAT::VARISAT.count(&cnf), af.count(semantics));
+        }
+    }
+}
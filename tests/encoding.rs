@@ -0,0 +1,367 @@
+mod common;
+
+use af::{
+    encoding::{Enconding, ParseError, ParseErrorKind},
+    graph::DotDrawable,
+    semantics::{Semantics, SemanticsType},
+    Attack, AF,
+};
+use common::{af, TWO_CYCLES};
+
+/** Errors of a text that shouldn't parse */
+fn errors(parsed: Enconding) -> Vec<ParseError> {
+    match parsed {
+        Enconding::ERROR(errors) => errors,
+        _ => panic!("parsed without errors"),
+    }
+}
+
+fn error(line: usize, column: usize, kind: ParseErrorKind) -> ParseError {
+    ParseError { line, column, kind }
+}
+
+#[test]
+fn encodings_round_trip() {
+    let af = af(TWO_CYCLES);
+    for text in [
+        af.to_simple(),
+        af.to_tgf(),
+        af.to_aif(None),
+        af.to_graphml(&[]),
+    ] {
+        let parsed = AF::from(Enconding::parse(text));
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", af));
+    }
+    // names as they come from AIF, DOT or GraphML files
+    let names = [
+        "Taxes are high",
+        "We should cut spending",
+        "x, (or y)",
+        "say \"hi\"",
+        "back\\slash",
+        "spending (2024)",
+    ];
+    let labels = names
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    let attacks = vec![
+        (labels[0].clone(), labels[1].clone()),
+        (labels[2].clone(), labels[3].clone()),
+        (labels[4].clone(), labels[5].clone()),
+        (labels[5].clone(), labels[0].clone()),
+    ];
+    let af = AF::from(Enconding::SIMPLE(labels, attacks));
+    for text in [
+        af.to_simple(),
+        af.to_tgf(),
+        af.to_aif(None),
+        af.to_graphml(&[]),
+    ] {
+        let parsed = AF::from(Enconding::parse(text.clone()));
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", af), "{}", text);
+    }
+}
+
+#[test]
+fn iccma() {
+    let text = "p af 3\n# a comment\n1 2\n\n2 3\n3 3\n";
+    let Enconding::ICCMA(num_of_args, attacks) = Enconding::parse(String::from(text)) else {
+        panic!("not read as ICCMA");
+    };
+    assert_eq!((num_of_args, attacks), (3, vec![(1, 2), (2, 3), (3, 3)]));
+    let af = AF::from(Enconding::parse(String::from(text)));
+    assert_eq!(af.arg_names(), vec!["1", "2", "3"]);
+    let attacks = af
+        .attacks
+        .iter()
+        .map(|&Attack(origin, target)| (origin, target))
+        .collect::<Vec<_>>();
+    assert_eq!(attacks, vec![(0, 1), (1, 2), (2, 2)]);
+}
+
+#[test]
+fn iccma_errors() {
+    assert_eq!(
+        errors(Enconding::parse_iccma(String::from("1 2\n"))),
+        vec![error(
+            1,
+            1,
+            ParseErrorKind::SYNTAX(String::from("missing p af header"))
+        )]
+    );
+    assert_eq!(
+        errors(Enconding::parse(String::from("p af 2\n1 3\n2 x\n1\n"))),
+        vec![
+            error(2, 3, ParseErrorKind::UNDECLARED(String::from("3"))),
+            error(
+                3,
+                3,
+                ParseErrorKind::SYNTAX(String::from("x is not a number"))
+            ),
+            error(
+                4,
+                1,
+                ParseErrorKind::SYNTAX(String::from("an attack needs an origin and a target"))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn diagnostics() {
+    let text = "arg(a).\natt(a, b).\narg(a).\nfoo(x).\natt(a).\narg(c)\n";
+    assert_eq!(
+        errors(Enconding::parse(String::from(text))),
+        vec![
+            error(2, 8, ParseErrorKind::UNDECLARED(String::from("b"))),
+            error(3, 5, ParseErrorKind::DUPLICATE(String::from("a"))),
+            error(4, 1, ParseErrorKind::PREDICATE(String::from("foo"))),
+            error(5, 4, ParseErrorKind::ARITY(String::from("att"), 1)),
+            error(6, 7, ParseErrorKind::PERIOD),
+        ]
+    );
+    assert_eq!(
+        error(5, 4, ParseErrorKind::ARITY(String::from("att"), 1)).to_string(),
+        "5:4: att takes 2 argument(s) but 1 were given"
+    );
+    // ASP comments
+    let text = "% a benchmark\narg(a).\n  % indented\narg(b). % b\natt(a,b). att(b,a).%\n";
+    let Enconding::SIMPLE(labels, attacks) = Enconding::parse(String::from(text)) else {
+        panic!("not read as APX");
+    };
+    assert_eq!((labels.len(), attacks.len()), (2, 2));
+    // columns count characters, not bytes
+    assert_eq!(
+        errors(Enconding::parse(String::from("arg(é).\natt(é, ü).\n"))),
+        vec![error(2, 8, ParseErrorKind::UNDECLARED(String::from("ü")))]
+    );
+}
+
+#[test]
+fn aif_import() {
+    // an OVA map with numeric ids: 1 supports 2 through RA 10, 3 attacks that inference through CA 20,
+    // 4 attacks 1 through CA 21. 3 repeats the text of 2, and 4 has none
+    let text = r#"{
+        "nodes": [
+            {"nodeID": 1, "text": "Taxes are high", "type": "I"},
+            {"nodeID": 2, "text": "We should cut spending", "type": "I"},
+            {"nodeID": 3, "text": " We should cut spending ", "type": "I"},
+            {"nodeID": 4, "text": "", "type": "I"},
+            {"nodeID": 10, "text": "Default Inference", "type": "RA"},
+            {"nodeID": 20, "text": "Default Conflict", "type": "CA"},
+            {"nodeID": "21", "text": "Default Conflict", "type": "CA"},
+            {"nodeID": 30, "text": "Bob: taxes are high", "type": "L"},
+            {"nodeID": 31, "text": "Asserting", "type": "YA"}
+        ],
+        "edges": [
+            {"edgeID": 1, "fromID": 1, "toID": 10},
+            {"edgeID": 2, "fromID": 10, "toID": 2},
+            {"edgeID": 3, "fromID": 3, "toID": 20},
+            {"edgeID": 4, "fromID": 20, "toID": 10},
+            {"edgeID": 5, "fromID": "4", "toID": 21},
+            {"edgeID": 6, "fromID": 21, "toID": 1},
+            {"edgeID": 7, "fromID": 30, "toID": 31},
+            {"edgeID": 8, "fromID": 31, "toID": 1}
+        ],
+        "locutions": []
+    }"#;
+    let Enconding::AIF(labels, attacks) = Enconding::parse(String::from(text)) else {
+        panic!("not read as AIF");
+    };
+    assert_eq!(
+        labels,
+        vec![
+            "Taxes are high",
+            "We should cut spending",
+            "We should cut spending (3)",
+            "(4)"
+        ]
+    );
+    let attack = |origin: &str, target: &str| (String::from(origin), String::from(target));
+    assert_eq!(
+        attacks,
+        vec![
+            attack("We should cut spending (3)", "We should cut spending"),
+            attack("(4)", "Taxes are high"),
+        ]
+    );
+    let syntax = |message: &str| vec![error(1, 1, ParseErrorKind::SYNTAX(String::from(message)))];
+    assert_eq!(
+        errors(Enconding::parse(String::from("{\"nodes\": []}"))),
+        syntax("an AIF map needs nodes and edges")
+    );
+    assert_eq!(
+        errors(Enconding::parse(String::from(
+            "{\"nodes\": [{\"nodeID\": 1}], \"edges\": []}"
+        ))),
+        syntax("every node needs a nodeID and a type")
+    );
+    assert_eq!(
+        errors(Enconding::parse(String::from(
+            "{\"nodes\": [], \"edges\": [{\"fromID\": 1}]}"
+        ))),
+        syntax("every edge needs a fromID and a toID")
+    );
+}
+
+#[test]
+fn graphml_extensions() {
+    let af = af(TWO_CYCLES);
+    let labellings = af.get_semantics(SemanticsType::PREFERRED);
+    assert_eq!(labellings.len(), 4);
+    let graphml = af.to_graphml(&labellings);
+    let document = roxmltree::Document::parse(&graphml).unwrap();
+    // the attribute name of every key
+    let keys = document
+        .descendants()
+        .filter(|node| node.has_tag_name("key"))
+        .map(|key| {
+            assert_eq!(key.attribute("for"), Some("node"));
+            (
+                key.attribute("id").unwrap(),
+                key.attribute("attr.name").unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let names = (1..=labellings.len())
+        .map(|k| format!("extension_{}", k))
+        .collect::<Vec<_>>();
+    assert_eq!(keys.iter().map(|key| key.1).collect::<Vec<_>>(), names);
+    let nodes = document
+        .descendants()
+        .filter(|node| node.has_tag_name("node"))
+        .collect::<Vec<_>>();
+    assert_eq!(nodes.len(), af.num_of_args);
+    for (i, node) in nodes.iter().enumerate() {
+        assert_eq!(node.attribute("id"), Some(af.arg_names()[i].as_str()));
+        let data = node
+            .children()
+            .filter(|data| data.has_tag_name("data"))
+            .map(|data| {
+                let key = data.attribute("key").unwrap();
+                let k = keys.iter().position(|(id, _)| *id == key).unwrap();
+                (k, data.text().unwrap().to_owned())
+            })
+            .collect::<Vec<_>>();
+        let expected = labellings
+            .iter()
+            .enumerate()
+            .map(|(k, labelling)| (k, format!("{:?}", labelling.0[i])))
+            .collect::<Vec<_>>();
+        assert_eq!(data, expected);
+    }
+    // the attributes are ignored when importing
+    let parsed = AF::from(Enconding::parse(graphml));
+    assert_eq!(format!("{:?}", parsed), format!("{:?}", af));
+}
+
+#[test]
+fn graphml_errors() {
+    let text = "<graphml>\n\
+        <graph edgedefault=\"undirected\">\n\
+        <node id=\"a\"/>\n\
+        <node id=\"b\"/>\n\
+        <node id=\"a\"/>\n\
+        <node/>\n\
+        <edge source=\"a\" target=\"b\"/>\n\
+        <edge source=\"a\" target=\"b\" directed=\"true\"/>\n\
+        <edge source=\"a\" target=\"c\" directed=\"true\"/>\n\
+        <edge source=\"d\" target=\"b\" directed=\"true\"/>\n\
+        <edge target=\"b\" directed=\"true\"/>\n\
+        </graph>\n\
+        </graphml>\n";
+    let syntax = |message: &str| ParseErrorKind::SYNTAX(String::from(message));
+    assert_eq!(
+        errors(Enconding::parse(String::from(text))),
+        vec![
+            error(5, 1, ParseErrorKind::DUPLICATE(String::from("a"))),
+            error(6, 1, syntax("a node needs an id")),
+            error(7, 1, syntax("undirected edges are not attacks")),
+            error(9, 1, ParseErrorKind::UNDECLARED(String::from("c"))),
+            error(10, 1, ParseErrorKind::UNDECLARED(String::from("d"))),
+            error(11, 1, syntax("an edge needs a source and a target")),
+        ]
+    );
+    // edges are directed by default
+    let text =
+        "<graphml><graph><node id=\"a\"/><edge source=\"a\" target=\"a\"/></graph></graphml>";
+    let Enconding::GRAPHML(labels, attacks) = Enconding::parse(String::from(text)) else {
+        panic!("not read as GraphML");
+    };
+    assert_eq!(labels, vec!["a"]);
+    assert_eq!(attacks, vec![(String::from("a"), String::from("a"))]);
+    assert_eq!(
+        errors(Enconding::parse(String::from("<graphml><graph>"))).len(),
+        1
+    );
+}
+
+#[test]
+fn dot_import() {
+    let text = "strict digraph \"af\" {\n\
+        rankdir=LR;\n\
+        node [shape=circle];\n\
+        // a comment\n\
+        a [fillcolor=\"green\"];\n\
+        a -> b -> \"c d\" [color=red];\n\
+        /* another\n comment */\n\
+        b:n -> a\n\
+        }\n";
+    let Enconding::DOT(labels, attacks) = Enconding::parse(String::from(text)) else {
+        panic!("not read as DOT");
+    };
+    assert_eq!(labels, vec!["a", "b", "c d"]);
+    let pair = |origin: &str, target: &str| (String::from(origin), String::from(target));
+    assert_eq!(
+        attacks,
+        vec![pair("a", "b"), pair("b", "c d"), pair("b", "a")]
+    );
+}
+
+#[test]
+fn dot_errors() {
+    let syntax = |line, column, message: &str| {
+        vec![error(
+            line,
+            column,
+            ParseErrorKind::SYNTAX(String::from(message)),
+        )]
+    };
+    assert_eq!(
+        errors(Enconding::parse_dot(String::from("graph { a -- b }"))),
+        syntax(1, 1, "expected digraph")
+    );
+    assert_eq!(
+        errors(Enconding::parse(String::from("digraph {\n  a -- b\n}"))),
+        syntax(2, 5, "undirected edges are not attacks")
+    );
+    assert_eq!(
+        errors(Enconding::parse(String::from("digraph {\n  \"a -> b\n}"))),
+        syntax(2, 3, "unterminated string")
+    );
+    assert_eq!(
+        errors(Enconding::parse(String::from("digraph {\n  a -> b;\n"))),
+        syntax(2, 1, "expected }")
+    );
+}
+
+#[test]
+fn dot_round_trip() {
+    let names = ["a", "x\\y", "say \"hi\"", "two words"].map(String::from);
+    let attacks = vec![
+        (names[0].clone(), names[1].clone()),
+        (names[1].clone(), names[2].clone()),
+        (names[2].clone(), names[3].clone()),
+        (names[3].clone(), names[3].clone()),
+    ];
+    let af = AF::from(Enconding::DOT(names.to_vec(), attacks));
+    let grounded = af.grounded();
+    for dot in [af.to_dot(None), af.to_dot(Some(&grounded))] {
+        let parsed = AF::from(Enconding::parse(dot.clone()));
+        assert_eq!(parsed.arg_names(), af.arg_names());
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", af));
+        // exporting again gives the same text, names don't grow escapes
+        assert_eq!(parsed.to_dot(None), af.to_dot(None));
+    }
+}
@@ -0,0 +1,142 @@
+use af::{
+    encoding::Enconding,
+    sat::{Backend, CnfFormula, ExtendFormula, Lit, Varisat, SAT},
+    semantics::{Semantics, SemanticsType, SEMANTICS_TYPES},
+    AF,
+};
+
+#[test]
+fn assumptions_only_hold_for_one_call() {
+    let mut cnf = CnfFormula::new();
+    let a = cnf.new_var().positive();
+    let b = cnf.new_var().positive();
+    cnf.add_clause(&[a, b]);
+    let mut backend = Varisat::default();
+    backend.add_formula(&cnf);
+    assert_eq!(backend.solve(&[!a, !b]), Ok(false));
+    assert_eq!(backend.solve(&[!a]), Ok(true));
+    assert_eq!(backend.model(), Some(vec![!a, b]));
+    assert_eq!(backend.solve(&[]), Ok(true));
+}
+
+#[test]
+fn blocking_clauses_enumerate_every_model() {
+    let mut cnf = CnfFormula::new();
+    let a = cnf.new_var().positive();
+    let b = cnf.new_var().positive();
+    cnf.add_clause(&[a, b]);
+    let mut models = SAT::VARISAT.enumerate(&cnf).unwrap();
+    models.sort();
+    assert_eq!(models, vec![vec![a, b], vec![a, !b], vec![!a, b]]);
+}
+
+/* varisat behind a wrapper, as any other backend would be plugged in */
+struct Wrapped(Varisat);
+
+impl Backend for Wrapped {
+    fn add_clause(&mut self, clause: &[Lit]) {
+        self.0.add_clause(clause)
+    }
+    fn solve(&mut self, assumptions: &[Lit]) -> Result<bool, String> {
+        self.0.solve(assumptions)
+    }
+    fn model(&self) -> Option<Vec<Lit>> {
+        self.0.model()
+    }
+}
+
+#[test]
+fn backends_agree() {
+    let text =
+        "arg(a).\narg(b).\narg(c).\narg(d).\natt(a,b).\natt(b,a).\natt(b,c).\natt(c,d).\natt(d,c).";
+    let af = AF::from(Enconding::parse(String::from(text)));
+    let wrapped = af
+        .clone()
+        .with_solver(SAT::CUSTOM(|| Box::new(Wrapped(Varisat::default()))));
+    for &semantics in SEMANTICS_TYPES {
+        assert_eq!(
+            format!("{:?}", af.get_semantics(semantics)),
+            format!("{:?}", wrapped.get_semantics(semantics))
+        );
+        assert_eq!(af.count(semantics), wrapped.count(semantics));
+    }
+}
+
+/* the brute-force stub next to this file, standing for any DIMACS solver binary */
+#[cfg(unix)]
+fn stub() -> SAT {
+    let script = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/stub-solver.sh");
+    SAT::EXTERNAL(vec![String::from("sh"), String::from(script)])
+}
+
+#[cfg(unix)]
+#[test]
+fn external_solver_enumerates_with_blocking_clauses() {
+    let text = "arg(a).\narg(b).\narg(c).\natt(a,b).\natt(b,a).\natt(b,c).";
+    let af = AF::from(Enconding::parse(String::from(text)));
+    let external = af.clone().with_solver(stub());
+    for semantics in [
+        SemanticsType::COMPLETE,
+        SemanticsType::STABLE,
+        SemanticsType::PREFERRED,
+    ] {
+        let mut expected = af.get_semantics(semantics);
+        let mut found = external.get_semantics(semantics);
+        expected.sort_by_key(|labelling| format!("{:?}", labelling));
+        found.sort_by_key(|labelling| format!("{:?}", labelling));
+        assert_eq!(found, expected);
+    }
+    // assumptions
+    assert!(
+        external
+            .credulous(2, SemanticsType::COMPLETE)
+            .unwrap()
+            .accepted
+    );
+    assert!(
+        !external
+            .skeptical(2, SemanticsType::COMPLETE)
+            .unwrap()
+            .accepted
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn external_solver_failures_are_errors() {
+    let af = AF::from(Enconding::parse(String::from(
+        "arg(a).\narg(b).\natt(a,b).",
+    )));
+    let command = |args: &[&str]| {
+        let args = args.iter().map(|arg| String::from(*arg)).collect();
+        af.clone().with_solver(SAT::EXTERNAL(args))
+    };
+    let missing = command(&["/nonexistent/solver"]);
+    let error = missing.count(SemanticsType::COMPLETE).unwrap_err();
+    assert!(
+        error.starts_with("cannot run /nonexistent/solver"),
+        "{}",
+        error
+    );
+    assert_eq!(
+        command(&[]).count(SemanticsType::COMPLETE),
+        Err(String::from("no SAT solver command"))
+    );
+    // a solver answering `s UNKNOWN`, for every kind of search
+    let unknown = command(&["echo", "s UNKNOWN"]);
+    for &semantics in SEMANTICS_TYPES {
+        if semantics == SemanticsType::GROUNDED {
+            continue;
+        }
+        let mut labellings = unknown.labellings(semantics);
+        let error = labellings.next().unwrap().unwrap_err();
+        assert!(error.starts_with("echo gave no answer"), "{}", error);
+        assert!(labellings.next().is_none());
+        assert!(unknown.credulous(0, semantics).is_err());
+        assert!(unknown.skeptical(0, semantics).is_err());
+    }
+    let violation = unknown
+        .verify(&af.grounded(), SemanticsType::PREFERRED)
+        .unwrap_err();
+    assert!(violation.reason.starts_with("cannot be checked"));
+}
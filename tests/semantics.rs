@@ -0,0 +1,261 @@
+mod common;
+
+use af::{
+    sat::FormulaEncoding,
+    semantics::{
+        Acceptability::{self, IN, OUT, UNDEC},
+        Labelling, Semantics, SemanticsType, SEMANTICS_ABBREVIATION, SEMANTICS_TYPES,
+    },
+    AF,
+};
+use common::{af, CHAIN, TWO_CYCLES};
+
+/** Labellings of `semantics` in a canonical order */
+fn labellings(af: &AF, semantics: SemanticsType) -> Vec<Vec<Acceptability>> {
+    let mut labellings = af
+        .get_semantics(semantics)
+        .into_iter()
+        .map(|labelling| labelling.0)
+        .collect::<Vec<_>>();
+    labellings.sort_by_key(|labelling| format!("{:?}", labelling));
+    labellings
+}
+
+#[test]
+fn chain_has_a_single_complete_labelling() {
+    let af = af(CHAIN);
+    for semantics in [
+        SemanticsType::COMPLETE,
+        SemanticsType::GROUNDED,
+        SemanticsType::PREFERRED,
+        SemanticsType::STABLE,
+        SemanticsType::SEMISTABLE,
+        SemanticsType::IDEAL,
+    ] {
+        assert_eq!(labellings(&af, semantics), vec![vec![IN, OUT, IN]]);
+    }
+}
+
+#[test]
+fn two_cycles() {
+    let af = af(TWO_CYCLES);
+    assert_eq!(labellings(&af, SemanticsType::COMPLETE).len(), 9);
+    assert_eq!(labellings(&af, SemanticsType::ADMISSIBLE).len(), 9);
+    assert_eq!(labellings(&af, SemanticsType::PREFERRED).len(), 4);
+    assert_eq!(labellings(&af, SemanticsType::STABLE).len(), 4);
+    assert_eq!(af.grounded().0, vec![UNDEC; 4]);
+}
+
+#[test]
+fn acceptance() {
+    let af = af(TWO_CYCLES);
+    let credulous = af.credulous(0, SemanticsType::PREFERRED).unwrap();
+    assert!(credulous.accepted);
+    assert_eq!(
+        credulous.witness.map(|labelling| labelling.0[0].clone()),
+        Some(IN)
+    );
+    assert!(!af.skeptical(0, SemanticsType::PREFERRED).unwrap().accepted);
+}
+
+#[test]
+fn maximal_witnesses_are_maximal() {
+    // d is unattacked, so it's IN in every naive labelling
+    let example = af("arg(a).\narg(b).\narg(c).\narg(d).\natt(a,b).\natt(b,a).\natt(d,a).");
+    let witness = example.credulous(1, SemanticsType::NAIVE).unwrap().witness;
+    assert_eq!(witness.map(|labelling| labelling.0[3].clone()), Some(IN));
+    // frameworks of 10 arguments, each attack present with probability 1/5
+    let mut state: u64 = 88172645463325252;
+    for _ in 0..20 {
+        let mut text = String::new();
+        for i in 0..10 {
+            text.push_str(&format!("arg(a{i}).\n"));
+        }
+        for i in 0..10 {
+            for j in 0..10 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                if state.is_multiple_of(5) {
+                    text.push_str(&format!("att(a{i},a{j}).\n"));
+                }
+            }
+        }
+        let af = af(&text);
+        for semantics in [SemanticsType::PREFERRED, SemanticsType::NAIVE] {
+            for arg in 0..af.num_of_args {
+                if let Some(witness) = af.credulous(arg, semantics).unwrap().witness {
+                    assert_eq!(witness.0[arg], IN);
+                    assert_eq!(af.verify(&witness, semantics), Ok(()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn verification() {
+    let af = af(CHAIN);
+    let grounded = af.grounded();
+    assert!(af.verify(&grounded, SemanticsType::STABLE).is_ok());
+    let mut wrong = grounded.clone();
+    wrong.0[2] = UNDEC;
+    let violation = af.verify(&wrong, SemanticsType::COMPLETE).unwrap_err();
+    assert_eq!(violation.arg, Some(2));
+    // b is attacked by a but not OUT, a labelling that is never listed
+    let labelling = Labelling(vec![IN, UNDEC, UNDEC]);
+    for semantics in [SemanticsType::CONFLICTFREE, SemanticsType::ADMISSIBLE] {
+        assert_eq!(af.verify(&labelling, semantics).unwrap_err().arg, Some(1));
+    }
+    // every labelling is verified exactly when it's listed
+    for text in [CHAIN, TWO_CYCLES] {
+        let framework = common::af(text);
+        let mut all = vec![vec![]];
+        for _ in 0..framework.num_of_args {
+            all = all
+                .into_iter()
+                .flat_map(|l: Vec<Acceptability>| {
+                    [IN, OUT, UNDEC].map(|acc| [l.clone(), vec![acc]].concat())
+                })
+                .collect();
+        }
+        for (index, &semantics) in SEMANTICS_TYPES.iter().enumerate() {
+            let listed = labellings(&framework, semantics);
+            for l in &all {
+                let verified = framework.verify(&Labelling(l.clone()), semantics).is_ok();
+                assert_eq!(
+                    verified,
+                    listed.contains(l),
+                    "{} {:?}",
+                    SEMANTICS_ABBREVIATION[index],
+                    l
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn counting() {
+    for text in [CHAIN, TWO_CYCLES] {
+        let af = af(text);
+        for &semantics in SEMANTICS_TYPES {
+            assert_eq!(af.count(semantics), Ok(af.get_semantics(semantics).len()));
+        }
+    }
+}
+
+#[test]
+fn labellings_are_computed_on_demand() {
+    // 3^40 complete labellings, far too many to list
+    let mut text = String::new();
+    for k in 0..40 {
+        text.push_str(&format!(
+            "arg(a{k}).\narg(b{k}).\natt(a{k},b{k}).\natt(b{k},a{k}).\n"
+        ));
+    }
+    let af = af(&text);
+    assert_eq!(af.labellings(SemanticsType::COMPLETE).take(3).count(), 3);
+    assert_eq!(af.labellings(SemanticsType::PREFERRED).take(3).count(), 3);
+    assert_eq!(af.labellings(SemanticsType::SEMISTABLE).take(3).count(), 3);
+}
+
+#[test]
+fn searches_resume_after_known_labellings() {
+    let text =
+        "arg(a).\narg(b).\narg(c).\narg(d).\narg(e).\narg(f).\natt(a,b).\natt(b,a).\natt(b,c).\n\
+        att(c,d).\natt(d,e).\natt(e,c).\natt(e,f).\natt(f,e).\natt(a,f).";
+    // the same framework written backwards, numbering its arguments the other way around
+    let mut lines = text.lines().collect::<Vec<_>>();
+    lines.reverse();
+    let (af, reversed) = (af(text), af(&lines.join("\n")));
+    let names = af.arg_names();
+    let reversed_names = reversed.arg_names();
+    for af in [af.clone(), af.with_encoding(FormulaEncoding::EXTENSION)] {
+        for (index, &semantics) in SEMANTICS_TYPES.iter().enumerate() {
+            let expected = labellings(&af, semantics);
+            // listed by the reversed framework, then given in the order of af
+            let listed = reversed
+                .labellings(semantics)
+                .map(|labelling| {
+                    let labelling = labelling.unwrap();
+                    Labelling(
+                        names
+                            .iter()
+                            .map(|name| {
+                                let arg = reversed_names.iter().position(|n| n == name).unwrap();
+                                labelling.0[arg].clone()
+                            })
+                            .collect(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            for k in 0..=listed.len() {
+                let known = &listed[..k];
+                let mut found = known.iter().map(|l| l.0.clone()).collect::<Vec<_>>();
+                found.extend(
+                    af.labellings_excluding(semantics, known)
+                        .map(|l| l.unwrap().0),
+                );
+                found.sort_by_key(|labelling| format!("{:?}", labelling));
+                assert_eq!(found, expected, "semantics {} after {}", index, k);
+            }
+        }
+    }
+}
+
+/** Whether the arguments labelled `label` in `a` are a strict subset of those in `b` */
+fn strictly_within(a: &[Acceptability], b: &[Acceptability], label: Acceptability) -> bool {
+    a != b && (0..a.len()).all(|i| a[i] != label || b[i] == label)
+}
+
+#[test]
+fn maximal_semantics_agree_with_complete_labellings() {
+    // odd and even cycles sharing arguments
+    let text =
+        "arg(a).\narg(b).\narg(c).\narg(d).\narg(e).\narg(f).\natt(a,b).\natt(b,a).\natt(b,c).\n\
+        att(c,d).\natt(d,e).\natt(e,c).\natt(e,f).\natt(f,e).\natt(a,f).";
+    for af in [af(text), af(af::presets::PRESETS[3])] {
+        let complete = labellings(&af, SemanticsType::COMPLETE);
+        let preferred = complete
+            .iter()
+            .filter(|l| !complete.iter().any(|m| strictly_within(l, m, IN)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let semi_stable = complete
+            .iter()
+            .filter(|l| !complete.iter().any(|m| strictly_within(m, l, UNDEC)))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(labellings(&af, SemanticsType::PREFERRED), preferred);
+        assert_eq!(labellings(&af, SemanticsType::SEMISTABLE), semi_stable);
+    }
+}
+
+#[test]
+fn encodings_agree() {
+    let text = "arg(a).\narg(b).\narg(c).\narg(d).\narg(e).\natt(a,b).\natt(b,a).\natt(b,c).\natt(c,d).\natt(d,e).\natt(e,c).\natt(e,e).";
+    for labelling in [af(text), af(TWO_CYCLES)] {
+        let extension = labelling.clone().with_encoding(FormulaEncoding::EXTENSION);
+        for &semantics in SEMANTICS_TYPES {
+            let expected = labellings(&labelling, semantics);
+            assert_eq!(labellings(&extension, semantics), expected);
+            assert_eq!(extension.count(semantics), Ok(expected.len()));
+            for arg in 0..labelling.num_of_args {
+                let credulous = labelling.credulous(arg, semantics).unwrap().accepted;
+                let skeptical = labelling.skeptical(arg, semantics).unwrap().accepted;
+                assert_eq!(
+                    extension.credulous(arg, semantics).unwrap().accepted,
+                    credulous
+                );
+                assert_eq!(
+                    extension.skeptical(arg, semantics).unwrap().accepted,
+                    skeptical
+                );
+            }
+            for found in extension.get_semantics(semantics) {
+                assert_eq!(extension.verify(&found, semantics), Ok(()));
+            }
+        }
+    }
+}
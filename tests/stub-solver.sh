@@ -0,0 +1,34 @@
+#!/bin/sh
+# Stub SAT solver for the tests: tries every assignment of a (small) DIMACS CNF file,
+# answering like competition solvers do.
+awk 'BEGIN { clauses = 0 }
+/^c/ { next }
+/^p/ { vars = $3; next }
+{
+    for (i = 1; i <= NF; i++) {
+        if ($i == 0) { clauses++; continue }
+        lits[clauses, size[clauses]++] = $i
+    }
+}
+END {
+    for (m = 0; m < 2 ^ vars; m++) {
+        ok = 1
+        for (c = 0; c < clauses && ok; c++) {
+            sat = 0
+            for (k = 0; k < size[c] && !sat; k++) {
+                lit = lits[c, k]
+                value = int(m / 2 ^ ((lit < 0 ? -lit : lit) - 1)) % 2
+                if ((lit > 0 && value) || (lit < 0 && !value)) sat = 1
+            }
+            ok = sat
+        }
+        if (ok) {
+            printf "s SATISFIABLE\nv"
+            for (v = 1; v <= vars; v++) printf " %d", (int(m / 2 ^ (v - 1)) % 2) ? v : -v
+            print " 0"
+            exit 10
+        }
+    }
+    print "s UNSATISFIABLE"
+    exit 20
+}' "$1"
@@ -0,0 +1,39 @@
+#![cfg(feature = "web")]
+
+use af::{
+    sat::FormulaEncoding,
+    semantics::{Acceptability::IN, Labelling},
+    worker::{Request, Response},
+};
+
+#[test]
+fn messages_round_trip() {
+    let request = Request::SOLVE {
+        job: 3,
+        text: String::from("arg(a)."),
+        semantics: 0,
+        encoding: FormulaEncoding::EXTENSION,
+        known: vec![Labelling(vec![IN])],
+        wanted: 2,
+    };
+    match Request::from_json(&request.to_json()) {
+        Some(Request::SOLVE { job, known, .. }) => {
+            assert_eq!(job, 3);
+            assert_eq!(known, vec![Labelling(vec![IN])]);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let response = Response::FOUND {
+        job: 3,
+        labelling: Labelling(vec![IN]),
+    };
+    match Response::from_json(&response.to_json()) {
+        Some(Response::FOUND { job, labelling }) => {
+            assert_eq!(job, 3);
+            assert_eq!(labelling.0, vec![IN]);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(Request::from_json("{}").is_none());
+}